@@ -23,3 +23,21 @@ pub fn grl_1_b(reader: impl Read, mut writer: impl Write) {
         writeln!(writer, "NEGATIVE CYCLE").ok();
     }
 }
+
+#[verify::aizu_online_judge("GRL_1_B")]
+pub fn grl_1_b_spfa(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, vs, es, r, (graph, d): @DirectedGraphScanner::<usize, i64>::new(vs, es));
+    let cost = graph.spfa_ss::<OptionSp<AdditiveOperation<_>>, _>(r, &|eid| Some(d[eid]));
+    if let Some(cost) = cost {
+        for u in graph.vertices() {
+            match cost[u] {
+                Some(d) => writeln!(writer, "{}", d).ok(),
+                None => writeln!(writer, "INF").ok(),
+            };
+        }
+    } else {
+        writeln!(writer, "NEGATIVE CYCLE").ok();
+    }
+}
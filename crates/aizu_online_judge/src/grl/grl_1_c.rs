@@ -28,3 +28,24 @@ pub fn grl_1_c(reader: impl Read, mut writer: impl Write) {
         }
     }
 }
+
+#[verify::aizu_online_judge("GRL_1_C")]
+pub fn grl_1_c_johnson(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, vs, es, (graph, d): @DirectedGraphScanner::<usize, i64>::new(vs, es));
+    if let Some(cost) = graph.johnson_ap::<AdditiveOperation<_>, _>(&|eid| Some(d[eid])) {
+        for u in graph.vertices() {
+            for v in graph.vertices() {
+                match cost[u][v] {
+                    Some(d) => write!(writer, "{}", d),
+                    None => write!(writer, "INF"),
+                }
+                .ok();
+                write!(writer, "{}", if v + 1 == vs { '\n' } else { ' ' }).ok();
+            }
+        }
+    } else {
+        writeln!(writer, "NEGATIVE CYCLE").ok();
+    }
+}
@@ -18,8 +18,8 @@ pub fn grl_5_e(reader: impl Read, mut writer: impl Write) {
         .enumerate()
         .flat_map(|(u, it)| it.into_iter().map(move |v| (u, v)))
         .collect();
-    let mut graph = UndirectedSparseGraph::from_edges(n, edges);
-    let hld = HeavyLightDecomposition::new(0, &mut graph);
+    let graph = UndirectedSparseGraph::from_edges(n, edges);
+    let hld = HeavyLightDecomposition::new(0, &graph);
     type M = (AdditiveOperation<u64>, AdditiveOperation<u64>);
     let mut seg = LazySegmentTree::<RangeSumRangeAdd<_>>::from_vec(vec![(0u64, 1u64); n]);
 
@@ -0,0 +1,124 @@
+use super::BitSet;
+
+/// Basis of xor operation over [`BitSet`] of fixed width.
+#[derive(Debug, Clone)]
+pub struct BitSetXorBasis {
+    width: usize,
+    /// (pivot, reduced basis, coordinates, inserted basis)
+    bases: Vec<(usize, BitSet, BitSet, BitSet)>,
+}
+impl BitSetXorBasis {
+    /// Create a empty space of vectors with `width` bits.
+    pub fn new(width: usize) -> Self {
+        Self {
+            width,
+            bases: vec![],
+        }
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn rank(&self) -> usize {
+        self.bases.len()
+    }
+    /// Return (reduced basis, coordinate).
+    /// Coordinate means if i-th bit is 1, x was reduced by i-th inserted basis.
+    pub fn reduce(&self, x: &BitSet) -> (BitSet, BitSet) {
+        assert_eq!(self.width, x.size());
+        let mut x = x.clone();
+        let mut coord = BitSet::new(self.width);
+        for (i, (p, u, c, _)) in self.bases.iter().enumerate() {
+            if x.get(*p) {
+                coord ^= c;
+                coord.set(i, !coord.get(i));
+                x ^= u;
+            }
+        }
+        (x, coord)
+    }
+    /// Return true if inserted element cannot be consisted by current basis and be added as a new basis.
+    /// Return false if inserted element can be consisted by current basis.
+    pub fn insert(&mut self, x: &BitSet) -> bool {
+        let (y, coord) = self.reduce(x);
+        match y.find_first() {
+            Some(p) => {
+                self.bases.push((p, y, coord, x.clone()));
+                true
+            }
+            None => false,
+        }
+    }
+    /// Return coordinate if element can be consisted by current basis.
+    pub fn find(&self, x: &BitSet) -> Option<BitSet> {
+        let (y, coord) = self.reduce(x);
+        if y.find_first().is_none() {
+            Some(coord)
+        } else {
+            None
+        }
+    }
+    /// Return inserted elements whose xor is `x` if exists.
+    pub fn basis(&self, x: &BitSet) -> Option<Vec<BitSet>> {
+        self.find(x).map(|coord| {
+            self.bases
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| coord.get(i))
+                .map(|(_, (_, _, _, b))| b.clone())
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rand, tools::Xorshift};
+
+    fn random_bitset(rng: &mut Xorshift, width: usize, p: f64) -> BitSet {
+        let mut x = BitSet::new(width);
+        for i in 0..width {
+            x.set(i, rng.gen_bool(p));
+        }
+        x
+    }
+
+    #[test]
+    fn test_bitset_xor_basis() {
+        let mut rng = Xorshift::default();
+        for _ in 0..100 {
+            rand!(rng, width: (1..200), k: (0..width * 2));
+            let p = [0.02, 0.5][rng.gen(0..2)];
+            let mut basis = BitSetXorBasis::new(width);
+            let mut inserted = vec![];
+            for _ in 0..k {
+                let spanned = rng.gen_bool(0.3);
+                let x = if spanned {
+                    let mut x = BitSet::new(width);
+                    for y in inserted.iter() {
+                        if rng.gen_bool(0.5) {
+                            x ^= y;
+                        }
+                    }
+                    x
+                } else {
+                    random_bitset(&mut rng, width, p)
+                };
+                let b = basis.basis(&x);
+                assert!(!spanned || b.is_some());
+                match b {
+                    Some(b) => {
+                        let s = b.iter().fold(BitSet::new(width), |acc, b| acc ^ b);
+                        assert_eq!(s, x);
+                        assert!(!basis.clone().insert(&x));
+                    }
+                    None => {
+                        assert!(basis.insert(&x));
+                        inserted.push(x);
+                    }
+                }
+                assert_eq!(basis.rank(), inserted.len());
+            }
+        }
+    }
+}
@@ -3,6 +3,7 @@ use super::{binary_search, MInt, MIntBase, One, Zero};
 #[derive(Debug, Clone)]
 pub struct IndependentSubSet<M: MIntBase> {
     pub n: usize,
+    pub g: Vec<usize>,
     pub ind: Vec<MInt<M>>,
 }
 impl<M: MIntBase> IndependentSubSet<M> {
@@ -14,8 +15,13 @@ impl<M: MIntBase> IndependentSubSet<M> {
             let v = s.trailing_zeros() as usize;
             ind.push(ind[s - (1 << v)] + ind[s & !(g[v] | 1 << v)]);
         }
-        Self { n, ind }
+        Self {
+            n,
+            g: g.to_vec(),
+            ind,
+        }
     }
+    /// Counts colorings modulo `M`, so it may rarely be a false negative.
     pub fn k_colorable(&self, k: usize) -> bool {
         !self
             .ind
@@ -30,4 +36,104 @@ impl<M: MIntBase> IndependentSubSet<M> {
     pub fn chromatic_number(&self) -> usize {
         binary_search(|&k| self.k_colorable(k), self.n, 0)
     }
+    /// Coloring with the smallest number of colors, colors are in `0..chromatic_number`.
+    ///
+    /// Like [`k_colorable`](Self::k_colorable), counts are taken modulo `M`, so this returns
+    /// `None` in the unlikely case that the count of every valid choice is divisible by the modulus.
+    pub fn optimal_coloring(&self) -> Option<Vec<usize>> {
+        let n = self.n;
+        let k = self.chromatic_number();
+        let mut is_ind = vec![true; 1 << n];
+        for s in 1usize..1 << n {
+            let v = s.trailing_zeros() as usize;
+            is_ind[s] = is_ind[s - (1 << v)] && s & self.g[v] == 0;
+        }
+        let mut color = vec![0; n];
+        let mut rest = (1usize << n) - 1;
+        let mut f = vec![MInt::<M>::zero(); 1 << n];
+        for c in (0..k).rev() {
+            // f[s]: number of c-tuples of independent sets whose union is s
+            for (f, d) in f.iter_mut().zip(&self.ind) {
+                *f = d.pow(c);
+            }
+            for i in 0..n {
+                for s in 0usize..1 << n {
+                    if s >> i & 1 == 1 {
+                        let x = f[s ^ 1 << i];
+                        f[s] -= x;
+                    }
+                }
+            }
+            let low = rest & rest.wrapping_neg();
+            let other = rest ^ low;
+            let mut sub = other;
+            loop {
+                let s = sub | low;
+                if is_ind[s] && !f[rest ^ s].is_zero() {
+                    for (v, color) in color.iter_mut().enumerate() {
+                        if s >> v & 1 == 1 {
+                            *color = c;
+                        }
+                    }
+                    rest ^= s;
+                    break;
+                }
+                if sub == 0 {
+                    return None;
+                }
+                sub = (sub - 1) & other;
+            }
+        }
+        Some(color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{num::mint_basic::Modulo998244353, rand, tools::Xorshift};
+
+    #[test]
+    fn test_optimal_coloring() {
+        const Q: usize = 200;
+        const N: usize = 10;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (0..=N), p: (0..=10));
+            let mut g = vec![0usize; n];
+            for u in 0..n {
+                for v in 0..u {
+                    if rng.gen(0..10) < p {
+                        g[u] |= 1 << v;
+                        g[v] |= 1 << u;
+                    }
+                }
+            }
+            let ind = IndependentSubSet::<Modulo998244353>::from_adj_graph(&g);
+            let k = ind.chromatic_number();
+            let color = ind.optimal_coloring().unwrap();
+            assert!(color.iter().all(|&c| c < k));
+            for u in 0..n {
+                for v in 0..n {
+                    if g[u] >> v & 1 == 1 {
+                        assert_ne!(color[u], color[v]);
+                    }
+                }
+            }
+            if k > 0 {
+                let mut dp = vec![!0usize; 1 << n];
+                dp[0] = 0;
+                for s in 1usize..1 << n {
+                    let mut sub = s;
+                    while sub > 0 {
+                        if (0..n).all(|v| sub >> v & 1 == 0 || sub & g[v] == 0) {
+                            dp[s] = dp[s].min(dp[s ^ sub] + 1);
+                        }
+                        sub = (sub - 1) & s;
+                    }
+                }
+                assert_eq!(dp[(1 << n) - 1], k);
+            }
+        }
+    }
 }
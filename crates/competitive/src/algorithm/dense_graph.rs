@@ -0,0 +1,359 @@
+use super::BitSet;
+
+/// set of vertices used as adjacency of [`DenseGraph`]
+pub trait AdjacencyBits: Clone {
+    fn empty(n: usize) -> Self;
+    fn full(n: usize) -> Self;
+    fn contains(&self, i: usize) -> bool;
+    fn insert(&mut self, i: usize);
+    fn remove(&mut self, i: usize);
+    fn count(&self) -> usize;
+    fn intersection(&self, other: &Self) -> Self;
+    fn difference(&self, other: &Self) -> Self;
+    fn first(&self) -> Option<usize>;
+}
+
+macro_rules! impl_adjacency_bits_unsigned {
+    ($($t:ty)*) => {
+        $(impl AdjacencyBits for $t {
+            fn empty(_n: usize) -> Self {
+                0
+            }
+            fn full(n: usize) -> Self {
+                if n >= std::mem::size_of::<$t>() * 8 {
+                    !0
+                } else {
+                    (1 << n) - 1
+                }
+            }
+            fn contains(&self, i: usize) -> bool {
+                self >> i & 1 == 1
+            }
+            fn insert(&mut self, i: usize) {
+                *self |= 1 << i;
+            }
+            fn remove(&mut self, i: usize) {
+                *self &= !(1 << i);
+            }
+            fn count(&self) -> usize {
+                self.count_ones() as usize
+            }
+            fn intersection(&self, other: &Self) -> Self {
+                self & other
+            }
+            fn difference(&self, other: &Self) -> Self {
+                self & !other
+            }
+            fn first(&self) -> Option<usize> {
+                if *self == 0 {
+                    None
+                } else {
+                    Some(self.trailing_zeros() as usize)
+                }
+            }
+        })*
+    };
+}
+impl_adjacency_bits_unsigned!(u32 u64 u128 usize);
+
+impl AdjacencyBits for BitSet {
+    fn empty(n: usize) -> Self {
+        BitSet::new(n)
+    }
+    fn full(n: usize) -> Self {
+        BitSet::ones(n)
+    }
+    fn contains(&self, i: usize) -> bool {
+        self.get(i)
+    }
+    fn insert(&mut self, i: usize) {
+        self.set(i, true);
+    }
+    fn remove(&mut self, i: usize) {
+        self.set(i, false);
+    }
+    fn count(&self) -> usize {
+        self.count_ones() as usize
+    }
+    fn intersection(&self, other: &Self) -> Self {
+        self & other
+    }
+    fn difference(&self, other: &Self) -> Self {
+        self & &!other
+    }
+    fn first(&self) -> Option<usize> {
+        self.find_first()
+    }
+}
+
+/// Undirected simple graph represented as adjacency bits
+#[derive(Debug, Clone)]
+pub struct DenseGraph<B> {
+    n: usize,
+    pub adj: Vec<B>,
+}
+
+impl<B> DenseGraph<B>
+where
+    B: AdjacencyBits,
+{
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            adj: vec![B::empty(n); n],
+        }
+    }
+    pub fn from_edges(n: usize, edges: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        let mut self_ = Self::new(n);
+        for (u, v) in edges {
+            self_.add_edge(u, v);
+        }
+        self_
+    }
+    pub fn vertices_size(&self) -> usize {
+        self.n
+    }
+    pub fn add_edge(&mut self, u: usize, v: usize) {
+        assert_ne!(u, v, "self-loops are not allowed");
+        self.adj[u].insert(v);
+        self.adj[v].insert(u);
+    }
+    pub fn has_edge(&self, u: usize, v: usize) -> bool {
+        self.adj[u].contains(v)
+    }
+    pub fn complement(&self) -> Self {
+        let full = B::full(self.n);
+        let adj = (0..self.n)
+            .map(|u| {
+                let mut a = full.difference(&self.adj[u]);
+                a.remove(u);
+                a
+            })
+            .collect();
+        Self { n: self.n, adj }
+    }
+    /// greedy coloring of `cand`: (vertices, colors) sorted by colors
+    fn color_sort(&self, cand: &B) -> (Vec<usize>, Vec<usize>) {
+        let mut order = Vec::with_capacity(cand.count());
+        let mut colors = Vec::with_capacity(cand.count());
+        let mut uncolored = cand.clone();
+        let mut k = 0;
+        while uncolored.first().is_some() {
+            k += 1;
+            let mut q = uncolored.clone();
+            while let Some(v) = q.first() {
+                q.remove(v);
+                q = q.difference(&self.adj[v]);
+                uncolored.remove(v);
+                order.push(v);
+                colors.push(k);
+            }
+        }
+        (order, colors)
+    }
+    fn clique_dfs(&self, clique: &mut Vec<usize>, mut cand: B, best: &mut Vec<usize>) {
+        let (order, colors) = self.color_sort(&cand);
+        for (&v, &c) in order.iter().zip(&colors).rev() {
+            if clique.len() + c <= best.len() {
+                return;
+            }
+            clique.push(v);
+            let next = cand.intersection(&self.adj[v]);
+            if next.first().is_some() {
+                self.clique_dfs(clique, next, best);
+            } else if clique.len() > best.len() {
+                *best = clique.clone();
+            }
+            clique.pop();
+            cand.remove(v);
+        }
+    }
+    /// maximum clique by branch and bound with greedy coloring bounds
+    pub fn maximum_clique(&self) -> Vec<usize> {
+        let mut best = vec![];
+        self.clique_dfs(&mut vec![], B::full(self.n), &mut best);
+        best.sort_unstable();
+        best
+    }
+    /// maximum independent set by branch and bound on the complement graph
+    pub fn maximum_independent_set(&self) -> Vec<usize> {
+        self.complement().maximum_clique()
+    }
+}
+
+impl DenseGraph<u64> {
+    /// subset dp over `[offset, n)`, `f(dp, s, v, rest)` where `v` is the lowest vertex of `s`
+    /// and `rest` is `s` without `v` and its neighbors
+    fn half_table<T, F>(&self, offset: usize, init: T, mut f: F) -> Vec<T>
+    where
+        T: Clone,
+        F: FnMut(&[T], usize, usize, usize) -> T,
+    {
+        let m = self.n - offset;
+        let mut dp = vec![init; 1 << m];
+        for s in 1usize..1 << m {
+            let v = s.trailing_zeros() as usize;
+            let rest = s & !(self.adj[v + offset] >> offset) as usize & !(1 << v);
+            dp[s] = f(&dp, s, v, rest);
+        }
+        dp
+    }
+    /// Enumerate `(subset, neighbors)` for independent subsets of `[0, h)`.
+    fn independent_subsets(&self, h: usize, mut f: impl FnMut(usize, u64)) {
+        let mut nb = vec![0u64; 1 << h];
+        let mut ind = vec![false; 1 << h];
+        ind[0] = true;
+        f(0, 0);
+        for s in 1usize..1 << h {
+            let v = s.trailing_zeros() as usize;
+            let t = s ^ 1 << v;
+            nb[s] = nb[t] | self.adj[v];
+            ind[s] = ind[t] && nb[t] >> v & 1 == 0;
+            if ind[s] {
+                f(s, nb[s]);
+            }
+        }
+    }
+    /// maximum independent set by meet-in-the-middle, O(2^{n/2}), n <= 40 or so
+    pub fn maximum_independent_set_mitm(&self) -> Vec<usize> {
+        let h = self.n / 2;
+        let best = self.half_table(h, 0usize, |dp, s, v, rest| {
+            let x = dp[s ^ 1 << v];
+            let y = dp[rest] | 1 << v;
+            if x.count_ones() >= y.count_ones() {
+                x
+            } else {
+                y
+            }
+        });
+        let mask = (1u64 << (self.n - h)) - 1;
+        let mut res = (0usize, 0usize);
+        self.independent_subsets(h, |s, nb| {
+            let t = best[(!nb >> h & mask) as usize];
+            if res.0.count_ones() + res.1.count_ones() < s.count_ones() + t.count_ones() {
+                res = (s, t);
+            }
+        });
+        (0..h)
+            .filter(|&i| res.0 >> i & 1 == 1)
+            .chain((h..self.n).filter(|&i| res.1 >> (i - h) & 1 == 1))
+            .collect()
+    }
+    /// number of independent sets including the empty set, O(2^{n/2}), n <= 40 or so
+    pub fn count_independent_sets(&self) -> u64 {
+        assert!(self.n < 64);
+        let h = self.n / 2;
+        let cnt = self.half_table(h, 1u64, |dp, s, v, rest| dp[s ^ 1 << v] + dp[rest]);
+        let mask = (1u64 << (self.n - h)) - 1;
+        let mut res = 0u64;
+        self.independent_subsets(h, |_, nb| res += cnt[(!nb >> h & mask) as usize]);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rand, tools::Xorshift};
+
+    fn gen_graph<B: AdjacencyBits>(rng: &mut Xorshift, n: usize) -> (DenseGraph<B>, Vec<u64>) {
+        let p = rng.randf();
+        let mut g = DenseGraph::<B>::new(n);
+        let mut adj = vec![0u64; n];
+        for u in 0..n {
+            for v in 0..u {
+                if rng.gen_bool(p) {
+                    g.add_edge(u, v);
+                    adj[u] |= 1 << v;
+                    adj[v] |= 1 << u;
+                }
+            }
+        }
+        (g, adj)
+    }
+
+    fn is_clique(adj: &[u64], vs: &[usize]) -> bool {
+        vs.iter()
+            .all(|&u| vs.iter().all(|&v| u == v || adj[u] >> v & 1 == 1))
+    }
+
+    fn is_independent(adj: &[u64], vs: &[usize]) -> bool {
+        vs.iter().all(|&u| vs.iter().all(|&v| adj[u] >> v & 1 == 0))
+    }
+
+    fn naive(adj: &[u64]) -> (usize, usize, u64) {
+        let n = adj.len();
+        let (mut clique, mut mis, mut count) = (0, 0, 0);
+        for s in 0u64..1 << n {
+            let vs: Vec<_> = (0..n).filter(|&i| s >> i & 1 == 1).collect();
+            if is_clique(adj, &vs) {
+                clique = clique.max(vs.len());
+            }
+            if is_independent(adj, &vs) {
+                mis = mis.max(vs.len());
+                count += 1;
+            }
+        }
+        (clique, mis, count)
+    }
+
+    #[test]
+    fn test_dense_graph() {
+        const Q: usize = 300;
+        const N: usize = 14;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (0..=N));
+            let (g, adj) = gen_graph::<u64>(&mut rng, n);
+            let (clique, mis, count) = naive(&adj);
+            let c = g.maximum_clique();
+            assert_eq!(c.len(), clique);
+            assert!(is_clique(&adj, &c));
+            let i = g.maximum_independent_set();
+            assert_eq!(i.len(), mis);
+            assert!(is_independent(&adj, &i));
+            let i = g.maximum_independent_set_mitm();
+            assert_eq!(i.len(), mis);
+            assert!(is_independent(&adj, &i));
+            assert_eq!(g.count_independent_sets(), count);
+
+            let g = DenseGraph::<BitSet>::from_edges(
+                n,
+                (0..n).flat_map(|u| {
+                    (0..u)
+                        .map(move |v| (u, v))
+                        .filter(|&(u, v)| adj[u] >> v & 1 == 1)
+                }),
+            );
+            let c = g.maximum_clique();
+            assert_eq!(c.len(), clique);
+            assert!(is_clique(&adj, &c));
+            assert_eq!(g.maximum_independent_set().len(), mis);
+        }
+    }
+
+    #[test]
+    fn test_dense_graph_large() {
+        const Q: usize = 10;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (30..=40));
+            let (g, adj) = gen_graph::<u64>(&mut rng, n);
+            let i = g.maximum_independent_set_mitm();
+            assert!(is_independent(&adj, &i));
+            assert_eq!(i.len(), g.maximum_independent_set().len());
+            let c = g.maximum_clique();
+            assert!(is_clique(&adj, &c));
+            assert_eq!(c.len(), g.complement().maximum_independent_set_mitm().len());
+            let g128 = DenseGraph::<u128>::from_edges(
+                n,
+                (0..n).flat_map(|u| {
+                    (0..u)
+                        .map(move |v| (u, v))
+                        .filter(|&(u, v)| adj[u] >> v & 1 == 1)
+                }),
+            );
+            assert_eq!(g128.maximum_clique().len(), c.len());
+        }
+    }
+}
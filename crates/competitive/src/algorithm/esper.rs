@@ -99,7 +99,9 @@ where
                 if coeff.is_none() {
                     eprintln!(
                         "failed to solve linear equations: key={:?} A={:?} b={:?}",
-                        key, &mat.data, &b
+                        key,
+                        mat.rows().collect::<Vec<_>>(),
+                        &b
                     );
                 }
                 (key, coeff)
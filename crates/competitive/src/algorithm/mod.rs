@@ -1,6 +1,7 @@
 //! algorithm
 
 use crate::algebra::{Group, Magma, Monoid, Unital};
+use crate::data_structure::BitSet;
 use crate::math::Matrix;
 use crate::num::{MInt, MIntBase, One, Zero};
 
@@ -10,12 +11,16 @@ pub use self::baby_step_giant_step::BabyStepGiantStep;
 pub use self::binary_search::*;
 #[codesnip::entry("BitDp")]
 pub use self::bitdp::{BitDp, Combinations, Subsets};
+#[codesnip::entry("BitSetXorBasis")]
+pub use self::bitset_xorbasis::BitSetXorBasis;
 #[codesnip::entry("chromatic_number")]
 pub use self::chromatic_number::IndependentSubSet;
 #[codesnip::entry("combinations")]
 pub use self::combinations::SliceCombinationsExt;
 #[codesnip::entry("ConvexHullTrick")]
 pub use self::convex_hull_trick::ConvexHullTrick;
+#[codesnip::entry("DenseGraph")]
+pub use self::dense_graph::{AdjacencyBits, DenseGraph};
 #[codesnip::entry("esper")]
 pub use self::esper::{EsperEstimator, EsperSolver};
 #[codesnip::entry("ImpartialGame")]
@@ -48,6 +53,8 @@ mod baby_step_giant_step;
 mod binary_search;
 #[cfg_attr(nightly, codesnip::entry("BitDp"))]
 mod bitdp;
+#[cfg_attr(nightly, codesnip::entry("BitSetXorBasis", include("BitSet")))]
+mod bitset_xorbasis;
 #[cfg_attr(
     nightly,
     codesnip::entry("chromatic_number", include("MIntBase", "binary_search"))
@@ -57,6 +64,8 @@ mod chromatic_number;
 mod combinations;
 #[cfg_attr(nightly, codesnip::entry("ConvexHullTrick"))]
 mod convex_hull_trick;
+#[cfg_attr(nightly, codesnip::entry("DenseGraph", include("BitSet")))]
+mod dense_graph;
 #[cfg_attr(nightly, codesnip::entry("esper", include("Matrix")))]
 mod esper;
 #[cfg_attr(nightly, codesnip::entry("ImpartialGame"))]
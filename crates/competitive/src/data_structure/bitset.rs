@@ -26,6 +26,10 @@ impl BitSet {
         self_
     }
     #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+    #[inline]
     pub fn get(&self, i: usize) -> bool {
         self.bits[i >> 6] & 1 << (i & 63) != 0
     }
@@ -37,6 +41,19 @@ impl BitSet {
             self.bits[i >> 6] &= !(1 << (i & 63));
         }
     }
+    /// Return `len` bits from `i` as an integer, `len <= 64`.
+    #[inline]
+    pub fn get_bits(&self, i: usize, len: usize) -> u64 {
+        if len == 0 {
+            return 0;
+        }
+        let (k, d) = (i >> 6, i & 63);
+        let mut x = self.bits[k] >> d;
+        if d + len > 64 && k + 1 < self.bits.len() {
+            x |= self.bits[k + 1] << (64 - d);
+        }
+        x & (!0u64 >> (64 - len))
+    }
     #[inline]
     pub fn count_ones(&self) -> u64 {
         self.bits.iter().map(|x| x.count_ones() as u64).sum()
@@ -45,6 +62,13 @@ impl BitSet {
     pub fn count_zeros(&self) -> u64 {
         self.size as u64 - self.count_ones()
     }
+    /// Return the smallest index of set bits.
+    pub fn find_first(&self) -> Option<usize> {
+        self.bits
+            .iter()
+            .position(|&x| x != 0)
+            .map(|i| i * 64 + self.bits[i].trailing_zeros() as usize)
+    }
     #[inline]
     fn trim(&mut self) {
         if self.size & 63 != 0 {
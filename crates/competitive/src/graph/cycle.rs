@@ -0,0 +1,379 @@
+use super::{DirectedSparseGraph, SparseGraph, UndirectedSparseGraph};
+
+impl<D> SparseGraph<D> {
+    fn other_end(&self, eid: usize, u: usize) -> usize {
+        let (a, b) = self.edges[eid];
+        a ^ b ^ u
+    }
+    fn simple_cycle_dfs<F>(
+        &self,
+        max_len: usize,
+        undirected: bool,
+        vs: &mut Vec<usize>,
+        es: &mut Vec<usize>,
+        on_path: &mut [bool],
+        f: &mut F,
+    ) where
+        F: FnMut(&[usize], &[usize]),
+    {
+        let (s, u) = (vs[0], *vs.last().unwrap());
+        for a in self.adjacencies(u) {
+            if a.to == s {
+                if undirected && !matches!(es.first(), Some(&e) if e < a.id) {
+                    continue;
+                }
+                es.push(a.id);
+                f(vs, es);
+                es.pop();
+            } else if a.to > s && !on_path[a.to] && vs.len() < max_len {
+                on_path[a.to] = true;
+                vs.push(a.to);
+                es.push(a.id);
+                self.simple_cycle_dfs(max_len, undirected, vs, es, on_path, f);
+                es.pop();
+                vs.pop();
+                on_path[a.to] = false;
+            }
+        }
+    }
+    fn for_each_simple_cycle_impl<F>(&self, max_len: usize, undirected: bool, mut f: F)
+    where
+        F: FnMut(&[usize], &[usize]),
+    {
+        if max_len == 0 {
+            return;
+        }
+        let mut on_path = vec![false; self.vertices_size()];
+        let mut vs = vec![];
+        let mut es = vec![];
+        for s in self.vertices() {
+            on_path[s] = true;
+            vs.push(s);
+            self.simple_cycle_dfs(max_len, undirected, &mut vs, &mut es, &mut on_path, &mut f);
+            vs.pop();
+            on_path[s] = false;
+        }
+    }
+}
+
+impl DirectedSparseGraph {
+    /// (vertices, edge ids) of a directed cycle, `edges[es[i]] = (vs[i], vs[i + 1])`
+    pub fn find_cycle(&self) -> Option<(Vec<usize>, Vec<usize>)> {
+        let n = self.vertices_size();
+        // 0: unvisited, 1: on stack, 2: finished
+        let mut state = vec![0u8; n];
+        let mut pe = vec![!0usize; n];
+        for r in self.vertices() {
+            if state[r] != 0 {
+                continue;
+            }
+            state[r] = 1;
+            let mut stack = vec![(r, self.adjacencies(r))];
+            while let Some((u, it)) = stack.last_mut() {
+                let u = *u;
+                match it.next() {
+                    Some(a) if state[a.to] == 0 => {
+                        state[a.to] = 1;
+                        pe[a.to] = a.id;
+                        stack.push((a.to, self.adjacencies(a.to)));
+                    }
+                    Some(a) if state[a.to] == 1 => {
+                        let mut es = vec![a.id];
+                        let mut v = u;
+                        while v != a.to {
+                            es.push(pe[v]);
+                            v = self.edges[pe[v]].0;
+                        }
+                        es.reverse();
+                        let vs = es.iter().map(|&e| self.edges[e].0).collect();
+                        return Some((vs, es));
+                    }
+                    Some(_) => {}
+                    None => {
+                        state[u] = 2;
+                        stack.pop();
+                    }
+                }
+            }
+        }
+        None
+    }
+    /// Enumerate (vertices, edge ids) of all simple cycles with length at most `max_len`.
+    pub fn for_each_simple_cycle<F>(&self, max_len: usize, f: F)
+    where
+        F: FnMut(&[usize], &[usize]),
+    {
+        self.for_each_simple_cycle_impl(max_len, false, f);
+    }
+}
+
+impl UndirectedSparseGraph {
+    /// 2-coloring, or (vertices, edge ids) of an odd cycle if not bipartite
+    ///
+    /// The edge `es[i]` connects `vs[i]` and `vs[(i + 1) % len]`.
+    pub fn bipartite_coloring(&self) -> Result<Vec<bool>, (Vec<usize>, Vec<usize>)> {
+        let n = self.vertices_size();
+        let mut depth = vec![!0usize; n];
+        let mut pe = vec![!0usize; n];
+        let mut queue = Vec::with_capacity(n);
+        for r in self.vertices() {
+            if depth[r] != !0 {
+                continue;
+            }
+            depth[r] = 0;
+            queue.push(r);
+            let mut i = queue.len() - 1;
+            while i < queue.len() {
+                let u = queue[i];
+                i += 1;
+                for a in self.adjacencies(u) {
+                    if depth[a.to] == !0 {
+                        depth[a.to] = depth[u] + 1;
+                        pe[a.to] = a.id;
+                        queue.push(a.to);
+                    } else if depth[a.to] == depth[u] {
+                        return Err(self.odd_cycle(u, a.to, a.id, &pe));
+                    }
+                }
+            }
+        }
+        Ok(depth.into_iter().map(|d| d % 2 == 1).collect())
+    }
+    /// odd cycle closed by the edge `eid` between `u` and `v` with the same bfs depth
+    fn odd_cycle(
+        &self,
+        mut u: usize,
+        mut v: usize,
+        eid: usize,
+        pe: &[usize],
+    ) -> (Vec<usize>, Vec<usize>) {
+        let (mut lv, mut le) = (vec![u], vec![]);
+        let (mut rv, mut re) = (vec![v], vec![eid]);
+        while u != v {
+            le.push(pe[u]);
+            u = self.other_end(pe[u], u);
+            lv.push(u);
+            re.push(pe[v]);
+            v = self.other_end(pe[v], v);
+            rv.push(v);
+        }
+        rv.pop();
+        lv.reverse();
+        le.reverse();
+        lv.extend(rv);
+        le.extend(re);
+        (lv, le)
+    }
+    /// length of the shortest cycle through each vertex, O(nm)
+    pub fn girth_per_vertex(&self) -> Vec<Option<usize>> {
+        let n = self.vertices_size();
+        let mut dist = vec![!0usize; n];
+        let mut branch = vec![!0usize; n];
+        let mut pe = vec![!0usize; n];
+        let mut queue = Vec::with_capacity(n);
+        let mut res = vec![None; n];
+        for r in self.vertices() {
+            let mut best = !0usize;
+            dist[r] = 0;
+            branch[r] = r;
+            queue.push(r);
+            let mut i = 0;
+            while i < queue.len() {
+                let u = queue[i];
+                i += 1;
+                if dist[u] * 2 + 1 >= best {
+                    break;
+                }
+                for a in self.adjacencies(u) {
+                    if a.id == pe[u] {
+                        continue;
+                    }
+                    if a.to == r && u == r {
+                        best = 1;
+                    } else if dist[a.to] == !0 {
+                        dist[a.to] = dist[u] + 1;
+                        branch[a.to] = if u == r { a.to } else { branch[u] };
+                        pe[a.to] = a.id;
+                        queue.push(a.to);
+                    } else if branch[a.to] != branch[u] {
+                        best = best.min(dist[u] + dist[a.to] + 1);
+                    }
+                }
+            }
+            if best != !0 {
+                res[r] = Some(best);
+            }
+            for u in queue.drain(..) {
+                dist[u] = !0;
+                pe[u] = !0;
+            }
+        }
+        res
+    }
+    /// length of the shortest cycle, O(nm)
+    pub fn girth(&self) -> Option<usize> {
+        self.girth_per_vertex().into_iter().flatten().min()
+    }
+    /// Enumerate (vertices, edge ids) of all simple cycles with length at most `max_len`.
+    ///
+    /// Each cycle is enumerated once regardless of its direction.
+    pub fn for_each_simple_cycle<F>(&self, max_len: usize, mut f: F)
+    where
+        F: FnMut(&[usize], &[usize]),
+    {
+        if max_len == 0 {
+            return;
+        }
+        for (eid, &(u, v)) in self.edges.iter().enumerate() {
+            if u == v {
+                f(&[u], &[eid]);
+            }
+        }
+        self.for_each_simple_cycle_impl(max_len, true, f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rand, tools::Xorshift};
+
+    fn check_cycle<D>(graph: &SparseGraph<D>, vs: &[usize], es: &[usize], directed: bool) {
+        let k = vs.len();
+        assert!(k >= 1);
+        assert_eq!(k, es.len());
+        let mut sorted = vs.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), k);
+        let mut sorted = es.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), k);
+        for i in 0..k {
+            let (u, v) = graph.edges[es[i]];
+            let (x, y) = (vs[i], vs[(i + 1) % k]);
+            assert!((u, v) == (x, y) || !directed && (v, u) == (x, y));
+        }
+    }
+
+    /// lengths and vertex sets of simple cycles by brute force over edge subsets
+    fn brute_cycles(n: usize, edges: &[(usize, usize)], directed: bool) -> Vec<(usize, Vec<bool>)> {
+        let m = edges.len();
+        let mut res = vec![];
+        for s in 1usize..1 << m {
+            let es: Vec<_> = (0..m).filter(|&i| s >> i & 1 == 1).collect();
+            let mut indeg = vec![0usize; n];
+            let mut outdeg = vec![0usize; n];
+            let mut uf = crate::data_structure::UnionFind::new(n);
+            for &e in es.iter() {
+                let (u, v) = edges[e];
+                outdeg[u] += 1;
+                indeg[v] += 1;
+                uf.unite(u, v);
+            }
+            let ok = (0..n).all(|u| {
+                if directed {
+                    indeg[u] == outdeg[u] && indeg[u] <= 1
+                } else {
+                    indeg[u] + outdeg[u] == 0 || indeg[u] + outdeg[u] == 2
+                }
+            });
+            let used: Vec<bool> = (0..n).map(|u| indeg[u] + outdeg[u] > 0).collect();
+            let r = edges[es[0]].0;
+            if ok && (0..n).all(|u| !used[u] || uf.same(u, r)) {
+                res.push((es.len(), used));
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn test_bipartite_coloring() {
+        const Q: usize = 1000;
+        const N: usize = 10;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), m: (0..=N));
+            rand!(rng, edges: [(0..n, 0..n); m]);
+            let graph = UndirectedSparseGraph::from_edges(n, edges.clone());
+            let bipartite =
+                (0usize..1 << n).any(|s| edges.iter().all(|&(u, v)| (s >> u ^ s >> v) & 1 == 1));
+            match graph.bipartite_coloring() {
+                Ok(color) => {
+                    assert!(bipartite);
+                    assert!(edges.iter().all(|&(u, v)| color[u] != color[v]));
+                }
+                Err((vs, es)) => {
+                    assert!(!bipartite);
+                    assert_eq!(vs.len() % 2, 1);
+                    check_cycle(&graph, &vs, &es, false);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_undirected_cycles() {
+        const Q: usize = 500;
+        const N: usize = 7;
+        const M: usize = 10;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), m: (0..=M), max_len: (0..=N));
+            rand!(rng, edges: [(0..n, 0..n); m]);
+            let graph = UndirectedSparseGraph::from_edges(n, edges.clone());
+            let brute = brute_cycles(n, &edges, false);
+            let mut expected = vec![0usize; n + 1];
+            let mut girth = vec![None; n];
+            for (len, used) in brute.iter() {
+                if *len <= max_len {
+                    expected[*len] += 1;
+                }
+                for u in 0..n {
+                    if used[u] && !matches!(girth[u], Some(g) if g <= *len) {
+                        girth[u] = Some(*len);
+                    }
+                }
+            }
+            let mut count = vec![0usize; n + 1];
+            graph.for_each_simple_cycle(max_len, |vs, es| {
+                check_cycle(&graph, vs, es, false);
+                count[vs.len()] += 1;
+            });
+            assert_eq!(count, expected);
+            assert_eq!(graph.girth_per_vertex(), girth);
+            assert_eq!(graph.girth(), girth.iter().flatten().min().cloned());
+        }
+    }
+
+    #[test]
+    fn test_directed_cycles() {
+        const Q: usize = 500;
+        const N: usize = 7;
+        const M: usize = 10;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), m: (0..=M), max_len: (0..=N));
+            rand!(rng, edges: [(0..n, 0..n); m]);
+            let graph = DirectedSparseGraph::from_edges(n, edges.clone());
+            let brute = brute_cycles(n, &edges, true);
+            let mut expected = vec![0usize; n + 1];
+            for (len, _) in brute.iter() {
+                if *len <= max_len {
+                    expected[*len] += 1;
+                }
+            }
+            let mut count = vec![0usize; n + 1];
+            graph.for_each_simple_cycle(max_len, |vs, es| {
+                check_cycle(&graph, vs, es, true);
+                count[vs.len()] += 1;
+            });
+            assert_eq!(count, expected);
+            match graph.find_cycle() {
+                Some((vs, es)) => check_cycle(&graph, &vs, &es, true),
+                None => assert!(brute.is_empty()),
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use super::{DirectedSparseGraph, UndirectedSparseGraph};
+
+/// Dominator tree of a directed graph from a root (Lengauer-Tarjan)
+#[derive(Debug, Clone)]
+pub struct DominatorTree<'a> {
+    graph: &'a DirectedSparseGraph,
+    root: usize,
+    idom: Vec<usize>,
+}
+impl std::ops::Index<usize> for DominatorTree<'_> {
+    type Output = usize;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.idom[index]
+    }
+}
+impl<'a> DominatorTree<'a> {
+    pub fn new(graph: &'a DirectedSparseGraph, root: usize) -> Self {
+        let n = graph.vertices_size();
+        let rgraph =
+            DirectedSparseGraph::from_edges(n, graph.edges.iter().map(|&(u, v)| (v, u)).collect());
+        // all arrays except `ord` are indexed by dfs order
+        let mut ord = vec![!0usize; n];
+        let mut vs = Vec::with_capacity(n);
+        let mut par = Vec::with_capacity(n);
+        let mut stack = vec![(root, !0usize)];
+        while let Some((u, p)) = stack.pop() {
+            if ord[u] != !0 {
+                continue;
+            }
+            ord[u] = vs.len();
+            vs.push(u);
+            par.push(p);
+            for a in graph.adjacencies(u).rev() {
+                if ord[a.to] == !0 {
+                    stack.push((a.to, ord[u]));
+                }
+            }
+        }
+        let k = vs.len();
+        let mut semi: Vec<usize> = (0..k).collect();
+        let mut label: Vec<usize> = (0..k).collect();
+        let mut anc = vec![!0usize; k];
+        let mut idom = vec![0usize; k];
+        let mut bucket = vec![vec![]; k];
+        let mut path = vec![];
+        for w in (1..k).rev() {
+            for a in rgraph.adjacencies(vs[w]) {
+                let v = ord[a.to];
+                if v == !0 {
+                    continue;
+                }
+                let u = Self::eval(v, &semi, &mut label, &mut anc, &mut path);
+                semi[w] = semi[w].min(semi[u]);
+            }
+            bucket[semi[w]].push(w);
+            let p = par[w];
+            anc[w] = p;
+            for v in std::mem::take(&mut bucket[p]) {
+                let u = Self::eval(v, &semi, &mut label, &mut anc, &mut path);
+                idom[v] = if semi[u] < semi[v] { u } else { p };
+            }
+        }
+        for w in 1..k {
+            if idom[w] != semi[w] {
+                idom[w] = idom[idom[w]];
+            }
+        }
+        let mut res = vec![!0usize; n];
+        for (w, &u) in vs.iter().enumerate() {
+            res[u] = vs[idom[w]];
+        }
+        Self {
+            graph,
+            root,
+            idom: res,
+        }
+    }
+    fn eval(
+        v: usize,
+        semi: &[usize],
+        label: &mut [usize],
+        anc: &mut [usize],
+        path: &mut Vec<usize>,
+    ) -> usize {
+        if anc[v] == !0 {
+            return v;
+        }
+        let mut x = v;
+        while anc[anc[x]] != !0 {
+            path.push(x);
+            x = anc[x];
+        }
+        while let Some(y) = path.pop() {
+            let a = anc[y];
+            if semi[label[a]] < semi[label[y]] {
+                label[y] = label[a];
+            }
+            anc[y] = anc[a];
+        }
+        label[v]
+    }
+}
+impl DominatorTree<'_> {
+    pub fn root(&self) -> usize {
+        self.root
+    }
+    /// immediate dominator, `idom(root) = Some(root)`
+    pub fn idom(&self, u: usize) -> Option<usize> {
+        if self.idom[u] == !0 {
+            None
+        } else {
+            Some(self.idom[u])
+        }
+    }
+    pub fn is_reachable(&self, u: usize) -> bool {
+        self.idom[u] != !0
+    }
+    /// immediate dominators, `!0` for unreachable vertices
+    pub fn idoms(&self) -> &[usize] {
+        &self.idom
+    }
+    /// dominator tree rooted at `root`, unreachable vertices are isolated
+    pub fn gen_tree(&self) -> UndirectedSparseGraph {
+        let edges = self
+            .graph
+            .vertices()
+            .filter(|&u| u != self.root && self.is_reachable(u))
+            .map(|u| (self.idom[u], u))
+            .collect();
+        UndirectedSparseGraph::from_edges(self.graph.vertices_size(), edges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rand, tools::Xorshift};
+
+    fn reachable(graph: &DirectedSparseGraph, root: usize, removed: usize) -> Vec<bool> {
+        let mut vis = vec![false; graph.vertices_size()];
+        if root == removed {
+            return vis;
+        }
+        vis[root] = true;
+        let mut stack = vec![root];
+        while let Some(u) = stack.pop() {
+            for a in graph.adjacencies(u) {
+                if a.to != removed && !vis[a.to] {
+                    vis[a.to] = true;
+                    stack.push(a.to);
+                }
+            }
+        }
+        vis
+    }
+
+    #[test]
+    fn test_dominator_tree() {
+        const Q: usize = 500;
+        const N: usize = 12;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), m: (0..=N * 3), root: (0..n));
+            rand!(rng, edges: [(0..n, 0..n); m]);
+            let graph = DirectedSparseGraph::from_edges(n, edges);
+            let dom = DominatorTree::new(&graph, root);
+            let base = reachable(&graph, root, n);
+            // dominators[v] = vertices dominating v
+            let mut dominators = vec![vec![]; n];
+            for x in 0..n {
+                let vis = reachable(&graph, root, x);
+                for v in 0..n {
+                    if base[v] && !vis[v] {
+                        dominators[v].push(x);
+                    }
+                }
+            }
+            for v in 0..n {
+                assert_eq!(dom.is_reachable(v), base[v]);
+                if !base[v] {
+                    assert_eq!(dom.idom(v), None);
+                    continue;
+                }
+                if v == root {
+                    assert_eq!(dom.idom(v), Some(root));
+                    continue;
+                }
+                // immediate dominator is the strict dominator dominated by all the others
+                let strict: Vec<_> = dominators[v].iter().cloned().filter(|&x| x != v).collect();
+                let idom = strict
+                    .iter()
+                    .cloned()
+                    .find(|&x| strict.iter().all(|y| dominators[x].contains(y)))
+                    .unwrap();
+                assert_eq!(dom[v], idom);
+            }
+            let tree = dom.gen_tree();
+            let depth = tree.tree_depth(root);
+            for v in 0..n {
+                if base[v] {
+                    assert_eq!(depth[v] as usize + 1, dominators[v].len());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use super::{DirectedSparseGraph, SparseGraph, UndirectedSparseGraph};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EulerianTrailError {
+    /// degree condition is violated at the vertex
+    Unbalanced(usize),
+    /// some edges are unreachable from the start vertex
+    Disconnected,
+}
+
+impl<D> SparseGraph<D> {
+    /// Hierholzer's algorithm: (vertices, edge ids)
+    fn hierholzer(&self, s: usize) -> Result<(Vec<usize>, Vec<usize>), EulerianTrailError> {
+        let mut ptr = self.start[..self.vertices_size()].to_vec();
+        let mut used = vec![false; self.edges_size()];
+        let mut stack = vec![(s, !0usize)];
+        let mut vs = Vec::with_capacity(self.edges_size() + 1);
+        let mut es = Vec::with_capacity(self.edges_size());
+        while let Some(&(u, _)) = stack.last() {
+            while ptr[u] < self.start[u + 1] && used[self.elist[ptr[u]].id] {
+                ptr[u] += 1;
+            }
+            if ptr[u] < self.start[u + 1] {
+                let a = self.elist[ptr[u]];
+                used[a.id] = true;
+                ptr[u] += 1;
+                stack.push((a.to, a.id));
+            } else {
+                let (u, eid) = stack.pop().unwrap();
+                vs.push(u);
+                if eid != !0 {
+                    es.push(eid);
+                }
+            }
+        }
+        if es.len() != self.edges_size() {
+            return Err(EulerianTrailError::Disconnected);
+        }
+        vs.reverse();
+        es.reverse();
+        Ok((vs, es))
+    }
+    fn any_edge_vertex(&self) -> usize {
+        self.edges.first().map(|&(u, _)| u).unwrap_or(0)
+    }
+}
+
+impl DirectedSparseGraph {
+    fn degree_diff(&self) -> Vec<isize> {
+        let mut diff = vec![0isize; self.vertices_size()];
+        for &(u, v) in self.edges.iter() {
+            diff[u] += 1;
+            diff[v] -= 1;
+        }
+        diff
+    }
+    /// (vertices, edge ids) of an Eulerian trail
+    ///
+    /// The trail starts at the vertex with out-degree one larger than in-degree if exists.
+    pub fn eulerian_trail(&self) -> Result<(Vec<usize>, Vec<usize>), EulerianTrailError> {
+        if self.vertices_size() == 0 {
+            return Ok((vec![], vec![]));
+        }
+        let diff = self.degree_diff();
+        let (mut s, mut t) = (None, None);
+        for u in self.vertices() {
+            match diff[u] {
+                0 => {}
+                1 if s.is_none() => s = Some(u),
+                -1 if t.is_none() => t = Some(u),
+                _ => return Err(EulerianTrailError::Unbalanced(u)),
+            }
+        }
+        self.hierholzer(s.unwrap_or_else(|| self.any_edge_vertex()))
+    }
+    /// (vertices, edge ids) of an Eulerian circuit from `start`
+    ///
+    /// `start` must be incident to an edge unless the graph has no edges.
+    pub fn eulerian_circuit(
+        &self,
+        start: usize,
+    ) -> Result<(Vec<usize>, Vec<usize>), EulerianTrailError> {
+        let diff = self.degree_diff();
+        if let Some(u) = self.vertices().find(|&u| diff[u] != 0) {
+            return Err(EulerianTrailError::Unbalanced(u));
+        }
+        self.hierholzer(start)
+    }
+}
+
+impl UndirectedSparseGraph {
+    /// (vertices, edge ids) of an Eulerian trail
+    ///
+    /// The trail starts at the smaller vertex with odd degree if exists.
+    pub fn eulerian_trail(&self) -> Result<(Vec<usize>, Vec<usize>), EulerianTrailError> {
+        if self.vertices_size() == 0 {
+            return Ok((vec![], vec![]));
+        }
+        let mut odd = self
+            .vertices()
+            .filter(|&u| self.adjacencies(u).len() % 2 == 1);
+        let s = odd.next();
+        if let Some(u) = odd.nth(1) {
+            return Err(EulerianTrailError::Unbalanced(u));
+        }
+        self.hierholzer(s.unwrap_or_else(|| self.any_edge_vertex()))
+    }
+    /// (vertices, edge ids) of an Eulerian circuit from `start`
+    ///
+    /// `start` must be incident to an edge unless the graph has no edges.
+    pub fn eulerian_circuit(
+        &self,
+        start: usize,
+    ) -> Result<(Vec<usize>, Vec<usize>), EulerianTrailError> {
+        if let Some(u) = self
+            .vertices()
+            .find(|&u| self.adjacencies(u).len() % 2 == 1)
+        {
+            return Err(EulerianTrailError::Unbalanced(u));
+        }
+        self.hierholzer(start)
+    }
+}
+
+/// cyclic sequence over `[0, k)` of length `k^n` which contains every word of length `n` exactly once
+pub fn de_bruijn_sequence(k: usize, n: usize) -> Vec<usize> {
+    if k == 0 || n == 0 {
+        return vec![];
+    }
+    let vsize = k.pow(n as u32 - 1);
+    let edges = (0..vsize * k).map(|e| (e / k, e % vsize)).collect();
+    let graph = DirectedSparseGraph::from_edges(vsize, edges);
+    let (_, es) = graph.eulerian_circuit(0).unwrap();
+    es.into_iter().map(|e| e % k).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rand, tools::Xorshift};
+    use std::collections::HashSet;
+
+    fn is_connected_edges<D>(graph: &SparseGraph<D>) -> bool {
+        let n = graph.vertices_size();
+        let mut uf = crate::data_structure::UnionFind::new(n);
+        for &(u, v) in graph.edges.iter() {
+            uf.unite(u, v);
+        }
+        let mut roots: Vec<_> = graph.edges.iter().map(|&(u, _)| uf.find(u)).collect();
+        roots.dedup();
+        roots.sort_unstable();
+        roots.dedup();
+        roots.len() <= 1
+    }
+
+    fn check_trail<D>(graph: &SparseGraph<D>, vs: &[usize], es: &[usize], directed: bool) {
+        assert_eq!(vs.len(), es.len() + 1);
+        let mut sorted = es.to_vec();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..graph.edges_size()).collect::<Vec<_>>());
+        for (i, &e) in es.iter().enumerate() {
+            let (u, v) = graph.edges[e];
+            if directed {
+                assert_eq!((u, v), (vs[i], vs[i + 1]));
+            } else {
+                assert!((u, v) == (vs[i], vs[i + 1]) || (v, u) == (vs[i], vs[i + 1]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_directed_eulerian_trail() {
+        const Q: usize = 2000;
+        const N: usize = 6;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), m: (0..=N * 2), mut u: (0..n), random: (0..2));
+            let mut edges = vec![];
+            for _ in 0..m {
+                let v = rng.gen(0..n);
+                if random == 0 {
+                    edges.push((u, v));
+                    u = v;
+                } else {
+                    edges.push((rng.gen(0..n), v));
+                }
+            }
+            let graph = DirectedSparseGraph::from_edges(n, edges);
+            let mut indeg = vec![0usize; n];
+            let mut outdeg = vec![0usize; n];
+            for &(u, v) in graph.edges.iter() {
+                outdeg[u] += 1;
+                indeg[v] += 1;
+            }
+            let plus = (0..n).filter(|&u| outdeg[u] > indeg[u]).count();
+            let minus = (0..n).filter(|&u| outdeg[u] < indeg[u]).count();
+            let balanced = (0..n).all(|u| outdeg[u].max(indeg[u]) - outdeg[u].min(indeg[u]) <= 1)
+                && plus <= 1
+                && minus <= 1;
+            let connected = is_connected_edges(&graph);
+            match graph.eulerian_trail() {
+                Ok((vs, es)) => check_trail(&graph, &vs, &es, true),
+                Err(EulerianTrailError::Unbalanced(_)) => assert!(!balanced),
+                Err(EulerianTrailError::Disconnected) => assert!(balanced && !connected),
+            }
+            let start = graph.edges.first().map(|&(u, _)| u).unwrap_or(0);
+            match graph.eulerian_circuit(start) {
+                Ok((vs, es)) => {
+                    check_trail(&graph, &vs, &es, true);
+                    assert_eq!(vs.first(), vs.last());
+                }
+                Err(EulerianTrailError::Unbalanced(u)) => assert_ne!(indeg[u], outdeg[u]),
+                Err(EulerianTrailError::Disconnected) => assert!(!connected),
+            }
+            if random == 0 {
+                assert!(graph.eulerian_trail().is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_undirected_eulerian_trail() {
+        const Q: usize = 2000;
+        const N: usize = 6;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), m: (0..=N * 2), mut u: (0..n), random: (0..2));
+            let mut edges = vec![];
+            for _ in 0..m {
+                let v = rng.gen(0..n);
+                if random == 0 {
+                    edges.push((u, v));
+                    u = v;
+                } else {
+                    edges.push((rng.gen(0..n), v));
+                }
+            }
+            let graph = UndirectedSparseGraph::from_edges(n, edges);
+            let odd = (0..n)
+                .filter(|&u| graph.adjacencies(u).len() % 2 == 1)
+                .count();
+            let connected = is_connected_edges(&graph);
+            match graph.eulerian_trail() {
+                Ok((vs, es)) => check_trail(&graph, &vs, &es, false),
+                Err(EulerianTrailError::Unbalanced(u)) => {
+                    assert!(odd > 2);
+                    assert_eq!(graph.adjacencies(u).len() % 2, 1);
+                }
+                Err(EulerianTrailError::Disconnected) => assert!(odd <= 2 && !connected),
+            }
+            let start = graph.edges.first().map(|&(u, _)| u).unwrap_or(0);
+            match graph.eulerian_circuit(start) {
+                Ok((vs, es)) => {
+                    check_trail(&graph, &vs, &es, false);
+                    assert_eq!(vs.first(), vs.last());
+                }
+                Err(EulerianTrailError::Unbalanced(_)) => assert!(odd > 0),
+                Err(EulerianTrailError::Disconnected) => assert!(!connected),
+            }
+            if random == 0 {
+                assert!(graph.eulerian_trail().is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_de_bruijn_sequence() {
+        assert_eq!(de_bruijn_sequence(0, 3), Vec::<usize>::new());
+        assert_eq!(de_bruijn_sequence(3, 0), Vec::<usize>::new());
+        for k in 1..=4 {
+            for n in 1..=5 {
+                let seq = de_bruijn_sequence(k, n);
+                let len = k.pow(n as u32);
+                assert_eq!(seq.len(), len);
+                let words: HashSet<Vec<usize>> = (0..len)
+                    .map(|i| (0..n).map(|j| seq[(i + j) % len]).collect())
+                    .collect();
+                assert_eq!(words.len(), len);
+                assert!(words.iter().flatten().all(|&c| c < k));
+            }
+        }
+    }
+}
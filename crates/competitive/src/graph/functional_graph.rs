@@ -0,0 +1,320 @@
+use super::{Adjacencies, AdjacencyIndex, GraphBase, UsizeGraph, Vertices};
+use crate::{algebra::Monoid, tree::LevelAncestor};
+use std::iter::{once, Once};
+
+/// Graph of a function `f: [0, n) -> [0, n)`
+#[derive(Debug, Clone)]
+pub struct FunctionalGraph {
+    next: Vec<usize>,
+    cycles: Vec<Vec<usize>>,
+    cycle_id: Vec<usize>,
+    /// index in the cycle of the first cycle vertex reached
+    cycle_pos: Vec<usize>,
+    /// tails form a forest rooted at cycle vertices
+    tails: LevelAncestor,
+}
+
+impl FunctionalGraph {
+    pub fn new(next: Vec<usize>) -> Self {
+        let n = next.len();
+        // 0: unvisited, 1: in process, 2: completed
+        let mut state = vec![0u8; n];
+        let mut cycles = vec![];
+        let mut cycle_id = vec![!0usize; n];
+        let mut cycle_pos = vec![!0usize; n];
+        let mut path = vec![];
+        for s in 0..n {
+            let mut u = s;
+            while state[u] == 0 {
+                state[u] = 1;
+                path.push(u);
+                u = next[u];
+            }
+            if state[u] == 1 {
+                let cycle = path.split_off(path.iter().position(|&v| v == u).unwrap());
+                for (i, &v) in cycle.iter().enumerate() {
+                    state[v] = 2;
+                    cycle_id[v] = cycles.len();
+                    cycle_pos[v] = i;
+                }
+                cycles.push(cycle);
+            }
+            while let Some(v) = path.pop() {
+                state[v] = 2;
+                cycle_id[v] = cycle_id[next[v]];
+                cycle_pos[v] = cycle_pos[next[v]];
+            }
+        }
+        let parent: Vec<_> = (0..n)
+            .map(|v| {
+                if cycles[cycle_id[v]][cycle_pos[v]] == v {
+                    !0
+                } else {
+                    next[v]
+                }
+            })
+            .collect();
+        Self {
+            next,
+            cycles,
+            cycle_id,
+            cycle_pos,
+            tails: LevelAncestor::new(&parent),
+        }
+    }
+    pub fn from_fn(n: usize, f: impl Fn(usize) -> usize) -> Self {
+        Self::new((0..n).map(f).collect())
+    }
+    /// graph whose vertices have exactly one adjacency
+    pub fn from_graph<'g, G>(graph: &'g G) -> Self
+    where
+        G: Vertices<'g> + Adjacencies<'g> + GraphBase<'g, VIndex = usize>,
+    {
+        let mut next: Vec<_> = graph.vertices().map(|_| !0).collect();
+        for u in graph.vertices() {
+            let mut adj = graph.adjacencies(u);
+            next[u] = adj.next().expect("out-degree must be 1").vindex();
+            assert!(adj.next().is_none(), "out-degree must be 1");
+        }
+        Self::new(next)
+    }
+    /// [`UsizeGraph`] with edges `u -> f(u)`
+    pub fn graph(&self) -> UsizeGraph<impl Fn(usize) -> Once<(usize, ())> + '_> {
+        UsizeGraph::new(self.next.len(), move |u| once((self.next[u], ())))
+    }
+    pub fn vertices_size(&self) -> usize {
+        self.next.len()
+    }
+    pub fn next(&self, u: usize) -> usize {
+        self.next[u]
+    }
+    pub fn cycles(&self) -> &[Vec<usize>] {
+        &self.cycles
+    }
+    /// id of the cycle reached from `u`
+    pub fn cycle_id(&self, u: usize) -> usize {
+        self.cycle_id[u]
+    }
+    pub fn on_cycle(&self, u: usize) -> bool {
+        self.tails.depth(u) == 0
+    }
+    /// number of steps to reach a cycle
+    pub fn dist_to_cycle(&self, u: usize) -> usize {
+        self.tails.depth(u)
+    }
+    /// first cycle vertex reached from `u`
+    pub fn cycle_entry(&self, u: usize) -> usize {
+        self.cycles[self.cycle_id[u]][self.cycle_pos[u]]
+    }
+    /// vertices from `u` before reaching the cycle
+    pub fn tail(&self, mut u: usize) -> Vec<usize> {
+        let mut res = Vec::with_capacity(self.dist_to_cycle(u));
+        while !self.on_cycle(u) {
+            res.push(u);
+            u = self.next[u];
+        }
+        res
+    }
+    /// $f^k(u)$ in O(1)
+    pub fn kth(&self, u: usize, k: usize) -> usize {
+        let d = self.tails.depth(u);
+        if k < d {
+            self.tails.la(u, k).unwrap()
+        } else {
+            let cycle = &self.cycles[self.cycle_id[u]];
+            cycle[(self.cycle_pos[u] + (k - d) % cycle.len()) % cycle.len()]
+        }
+    }
+    pub fn doubling<M>(&self, value: Vec<M::T>, max_k: usize) -> FunctionalGraphDoubling<M>
+    where
+        M: Monoid,
+    {
+        FunctionalGraphDoubling::new(&self.next, value, max_k)
+    }
+}
+
+/// binary lifting on a functional graph with a [`Monoid`] fold along `u, f(u), f(f(u)), ...`
+#[derive(Debug, Clone)]
+pub struct FunctionalGraphDoubling<M>
+where
+    M: Monoid,
+{
+    table: Vec<Vec<(usize, M::T)>>,
+}
+
+impl<M> FunctionalGraphDoubling<M>
+where
+    M: Monoid,
+{
+    /// `value[u]` is folded when stepping from `u`, supports `k <= max_k`
+    pub fn new(next: &[usize], value: Vec<M::T>, max_k: usize) -> Self {
+        assert_eq!(next.len(), value.len());
+        let mut table = vec![next.iter().cloned().zip(value).collect::<Vec<_>>()];
+        while 2 << (table.len() - 1) <= max_k {
+            let prev = table.last().unwrap();
+            let t = prev
+                .iter()
+                .map(|(v, x)| (prev[*v].0, M::operate(x, &prev[*v].1)))
+                .collect();
+            table.push(t);
+        }
+        Self { table }
+    }
+    /// ($f^k(u)$, value[u] * value[f(u)] * ... * value[f^{k-1}(u)])
+    pub fn fold(&self, mut u: usize, k: usize) -> (usize, M::T) {
+        assert!(k < 1 << self.table.len(), "k must be at most max_k");
+        let mut acc = M::unit();
+        for (j, t) in self.table.iter().enumerate() {
+            if k >> j & 1 == 1 {
+                acc = M::operate(&acc, &t[u].1);
+                u = t[u].0;
+            }
+        }
+        (u, acc)
+    }
+    pub fn kth(&self, u: usize, k: usize) -> usize {
+        self.fold(u, k).0
+    }
+    /// (k, $f^k(u)$, fold) with max `k < 2^t` such that `pred(fold(u, k).1)` holds,
+    /// where `pred` is monotone and `2^t` is the smallest power of two greater than `max_k`
+    pub fn max_steps<F>(&self, mut u: usize, pred: F) -> (usize, usize, M::T)
+    where
+        F: Fn(&M::T) -> bool,
+    {
+        let mut acc = M::unit();
+        let mut k = 0;
+        for (j, t) in self.table.iter().enumerate().rev() {
+            let nacc = M::operate(&acc, &t[u].1);
+            if pred(&nacc) {
+                acc = nacc;
+                u = t[u].0;
+                k |= 1 << j;
+            }
+        }
+        (k, u, acc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algebra::{LinearOperation, Magma, Unital},
+        graph::{ShortestPathExt, StandardSp},
+        num::mint_basic::MInt998244353,
+        rand,
+        tools::Xorshift,
+    };
+
+    #[test]
+    fn test_functional_graph() {
+        const Q: usize = 200;
+        const N: usize = 50;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), next: [0..n; n]);
+            let g = FunctionalGraph::new(next.clone());
+            let mut seen = vec![0usize; n];
+            for (i, cycle) in g.cycles().iter().enumerate() {
+                for (j, &u) in cycle.iter().enumerate() {
+                    seen[u] += 1;
+                    assert_eq!(next[u], cycle[(j + 1) % cycle.len()]);
+                    assert_eq!(g.cycle_id(u), i);
+                    assert!(g.on_cycle(u));
+                }
+            }
+            for u in 0..n {
+                let mut v = u;
+                for k in 0..=n * 2 {
+                    assert_eq!(g.kth(u, k), v);
+                    v = next[v];
+                }
+                let tail = g.tail(u);
+                assert_eq!(tail.len(), g.dist_to_cycle(u));
+                assert_eq!(g.cycle_entry(u), g.kth(u, tail.len()));
+                assert!(g.on_cycle(g.cycle_entry(u)));
+                assert!(tail.iter().all(|&v| !g.on_cycle(v) && seen[v] == 0));
+                assert_eq!(g.cycle_id(u), g.cycle_id(g.cycle_entry(u)));
+                assert_eq!(g.kth(u, 1_000_000_007), {
+                    let d = g.dist_to_cycle(u);
+                    let c = &g.cycles()[g.cycle_id(u)];
+                    let p = c.iter().position(|&x| x == g.cycle_entry(u)).unwrap();
+                    c[(p + (1_000_000_007 - d) % c.len()) % c.len()]
+                });
+            }
+
+            let graph = g.graph();
+            let g2 = FunctionalGraph::from_graph(&graph);
+            assert_eq!(g2.cycles(), g.cycles());
+            for u in 0..n {
+                let dist = graph
+                    .bfs_distance_ss::<StandardSp<crate::algebra::AdditiveOperation<usize>>, _>(
+                        u,
+                        &|_| 1,
+                    );
+                // first arrival along the naive walk from u
+                let mut expected = vec![!0usize; n];
+                let mut v = u;
+                for k in 0..n {
+                    if expected[v] == !0 {
+                        expected[v] = k;
+                    }
+                    v = next[v];
+                }
+                assert_eq!(dist, expected);
+                for v in (0..n).filter(|&v| expected[v] != !0) {
+                    assert_eq!(g.kth(u, dist[v]), v);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_functional_graph_doubling() {
+        const Q: usize = 200;
+        const N: usize = 30;
+        const K: usize = 200;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), next: [0..n; n], max_k: (0..=K));
+            let value: Vec<(MInt998244353, MInt998244353)> = (0..n)
+                .map(|_| {
+                    (
+                        MInt998244353::from(rng.gen(0..100u32)),
+                        MInt998244353::from(rng.gen(0..100u32)),
+                    )
+                })
+                .collect();
+            let g = FunctionalGraph::new(next.clone());
+            let d = g.doubling::<LinearOperation<_>>(value.clone(), max_k);
+            for u in 0..n {
+                let mut v = u;
+                let mut acc = LinearOperation::<MInt998244353>::unit();
+                for k in 0..=max_k {
+                    assert_eq!(d.fold(u, k), (v, acc));
+                    assert_eq!(d.kth(u, k), g.kth(u, k));
+                    acc = LinearOperation::operate(&acc, &value[v]);
+                    v = next[v];
+                }
+            }
+        }
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), next: [0..n; n], w: [0u64..10; n], limit: (0u64..500));
+            let d = FunctionalGraphDoubling::<crate::algebra::AdditiveOperation<u64>>::new(
+                &next,
+                w.clone(),
+                K,
+            );
+            for u in 0..n {
+                let (k, v, s) = d.max_steps(u, |&s| s <= limit);
+                let (mut ek, mut ev, mut es) = (0, u, 0);
+                while ek < (1 << 8) - 1 && es + w[ev] <= limit {
+                    es += w[ev];
+                    ev = next[ev];
+                    ek += 1;
+                }
+                assert_eq!((k, v, s), (ek, ev, es));
+            }
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use super::{DirectedSparseGraph, SparseGraph, SparseGraphConstruction};
+use crate::tools::{RandomSpec, Xorshift};
+
+fn random_permutation(n: usize, rng: &mut Xorshift) -> Vec<usize> {
+    let mut perm: Vec<usize> = (0..n).collect();
+    rng.shuffle(&mut perm);
+    perm
+}
+
+/// Connected graph with `n` vertices and `m` edges in addition to a spanning tree
+///
+/// It may have multiple edges but no self-loops.
+pub struct ConnectedGraph<N, M>(pub N, pub M);
+
+impl<D, N, M> RandomSpec<SparseGraph<D>> for ConnectedGraph<N, M>
+where
+    D: SparseGraphConstruction,
+    N: RandomSpec<usize>,
+    M: RandomSpec<usize>,
+{
+    fn rand(&self, rng: &mut Xorshift) -> SparseGraph<D> {
+        let n = rng.gen(&self.0);
+        let m = rng.gen(&self.1);
+        let perm = random_permutation(n, rng);
+        let mut edges: Vec<_> = (1..n).map(|v| (perm[rng.gen(0..v)], perm[v])).collect();
+        if n >= 2 {
+            for _ in 0..m {
+                let u = rng.gen(0..n);
+                let v = rng.gen(0..n - 1);
+                edges.push((u, if v >= u { v + 1 } else { v }));
+            }
+        }
+        for (u, v) in edges.iter_mut() {
+            if rng.gen_bool(0.5) {
+                std::mem::swap(u, v);
+            }
+        }
+        rng.shuffle(&mut edges);
+        SparseGraph::from_edges(n, edges)
+    }
+}
+
+/// DAG with `n` vertices and `m` edges
+pub struct Dag<N, M>(pub N, pub M);
+
+impl<N, M> RandomSpec<DirectedSparseGraph> for Dag<N, M>
+where
+    N: RandomSpec<usize>,
+    M: RandomSpec<usize>,
+{
+    fn rand(&self, rng: &mut Xorshift) -> DirectedSparseGraph {
+        let n = rng.gen(&self.0);
+        let m = rng.gen(&self.1);
+        let perm = random_permutation(n, rng);
+        let mut edges = Vec::with_capacity(m);
+        if n >= 2 {
+            for _ in 0..m {
+                let u = rng.gen(0..n);
+                let v = rng.gen(0..n - 1);
+                let v = if v >= u { v + 1 } else { v };
+                edges.push((perm[u.min(v)], perm[u.max(v)]));
+            }
+        }
+        DirectedSparseGraph::from_edges(n, edges)
+    }
+}
+
+/// Bipartite graph with `l` + `r` vertices and `m` edges
+///
+/// Generate (graph, color) where the color of `r` vertices is `true`, edges are directed from
+/// `false` to `true`.
+pub struct BipartiteGraph<L, R, M>(pub L, pub R, pub M);
+
+impl<D, L, R, M> RandomSpec<(SparseGraph<D>, Vec<bool>)> for BipartiteGraph<L, R, M>
+where
+    D: SparseGraphConstruction,
+    L: RandomSpec<usize>,
+    R: RandomSpec<usize>,
+    M: RandomSpec<usize>,
+{
+    fn rand(&self, rng: &mut Xorshift) -> (SparseGraph<D>, Vec<bool>) {
+        let l = rng.gen(&self.0);
+        let r = rng.gen(&self.1);
+        let m = rng.gen(&self.2);
+        let perm = random_permutation(l + r, rng);
+        let mut color = vec![false; l + r];
+        for &v in perm[l..].iter() {
+            color[v] = true;
+        }
+        let mut edges = Vec::with_capacity(m);
+        if l > 0 && r > 0 {
+            for _ in 0..m {
+                edges.push((perm[rng.gen(0..l)], perm[l + rng.gen(0..r)]));
+            }
+        }
+        (SparseGraph::from_edges(l + r, edges), color)
+    }
+}
+
+/// Directed graph with `n` vertices, `k` strongly connected components, and `m` edges in
+/// addition to a cycle of each component
+///
+/// Generate (graph, component id) where component ids are in topological order.
+pub struct SccGraph<N, K, M>(pub N, pub K, pub M);
+
+impl<N, K, M> RandomSpec<(DirectedSparseGraph, Vec<usize>)> for SccGraph<N, K, M>
+where
+    N: RandomSpec<usize>,
+    K: RandomSpec<usize>,
+    M: RandomSpec<usize>,
+{
+    fn rand(&self, rng: &mut Xorshift) -> (DirectedSparseGraph, Vec<usize>) {
+        let n = rng.gen(&self.0);
+        let k = rng.gen(&self.1);
+        let m = rng.gen(&self.2);
+        assert!(k <= n && (n == 0 || k > 0), "1 <= k <= n");
+        let perm = random_permutation(n, rng);
+        let mut comp = vec![0usize; n];
+        let mut groups = vec![vec![]; k];
+        for (i, &v) in perm.iter().enumerate() {
+            comp[v] = if i < k { i } else { rng.gen(0..k) };
+            groups[comp[v]].push(v);
+        }
+        let mut edges = Vec::with_capacity(n + m);
+        for group in groups.iter() {
+            if group.len() >= 2 {
+                for (i, &u) in group.iter().enumerate() {
+                    edges.push((u, group[(i + 1) % group.len()]));
+                }
+            }
+        }
+        if n >= 2 {
+            for _ in 0..m {
+                let u = rng.gen(0..n);
+                let v = rng.gen(0..n - 1);
+                let v = if v >= u { v + 1 } else { v };
+                edges.push(if comp[u] <= comp[v] { (u, v) } else { (v, u) });
+            }
+        }
+        rng.shuffle(&mut edges);
+        (DirectedSparseGraph::from_edges(n, edges), comp)
+    }
+}
+
+/// Generate (graph, weights of edges)
+pub struct WeightedGraph<G, W>(pub G, pub W);
+
+impl<D, G, T, W> RandomSpec<(SparseGraph<D>, Vec<T>)> for WeightedGraph<G, W>
+where
+    G: RandomSpec<SparseGraph<D>>,
+    W: RandomSpec<T>,
+{
+    fn rand(&self, rng: &mut Xorshift) -> (SparseGraph<D>, Vec<T>) {
+        let g = rng.gen(&self.0);
+        let w = (0..g.edges_size()).map(|_| rng.gen(&self.1)).collect();
+        (g, w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{StronglyConnectedComponent, UndirectedSparseGraph};
+
+    fn reachable<D>(g: &SparseGraph<D>, s: usize) -> Vec<bool> {
+        let mut vis = vec![false; g.vertices_size()];
+        let mut stack = vec![s];
+        vis[s] = true;
+        while let Some(u) = stack.pop() {
+            for a in g.adjacencies(u) {
+                if !vis[a.to] {
+                    vis[a.to] = true;
+                    stack.push(a.to);
+                }
+            }
+        }
+        vis
+    }
+
+    #[test]
+    fn connected_graph() {
+        const Q: usize = 1000;
+        const N: usize = 20;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            let (g, w): (UndirectedSparseGraph, Vec<i64>) =
+                rng.gen(WeightedGraph(ConnectedGraph(1..=N, 0..=N), -10i64..10));
+            assert!(g.edges_size() + 1 >= g.vertices_size());
+            assert!(g.edges_size() < g.vertices_size() + N);
+            assert_eq!(w.len(), g.edges_size());
+            assert!(w.iter().all(|w| (-10..10).contains(w)));
+            assert!(g.edges.iter().all(|&(u, v)| u != v));
+            assert!(reachable(&g, 0).into_iter().all(|b| b));
+        }
+    }
+
+    #[test]
+    fn dag() {
+        const Q: usize = 1000;
+        const N: usize = 20;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            let g = rng.gen(Dag(1..=N, 0..=N * 2));
+            for u in g.vertices() {
+                assert!(g.adjacencies(u).all(|a| !reachable(&g, a.to)[u]));
+            }
+        }
+    }
+
+    #[test]
+    fn bipartite_graph() {
+        const Q: usize = 1000;
+        const N: usize = 10;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            let (g, color): (DirectedSparseGraph, _) =
+                rng.gen(BipartiteGraph(0..=N, 0..=N, 0..=N * 2));
+            assert_eq!(g.vertices_size(), color.len());
+            assert!(g.edges.iter().all(|&(u, v)| !color[u] && color[v]));
+        }
+    }
+
+    #[test]
+    fn scc_graph() {
+        const Q: usize = 1000;
+        const N: usize = 20;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            let n = rng.gen(1..=N);
+            let k = rng.gen(1..=n);
+            let (g, comp) = rng.gen(SccGraph(n, k, 0..=N * 2));
+            let scc = StronglyConnectedComponent::new(&g);
+            assert_eq!(scc.size(), k);
+            for u in 0..n {
+                for v in 0..n {
+                    assert_eq!(scc[u] == scc[v], comp[u] == comp[v]);
+                }
+            }
+            assert!(g.edges.iter().all(|&(u, v)| comp[u] <= comp[v]));
+        }
+    }
+}
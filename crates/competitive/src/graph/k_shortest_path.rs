@@ -0,0 +1,376 @@
+use super::{DirectedSparseGraph, Group, OptionSp, ShortestPathExt, ShortestPathSemiRing};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, BinaryHeap},
+};
+
+/// persistent leftist heap stored in an arena
+struct PersistentLeftistHeap<T> {
+    nodes: Vec<(T, usize, usize, usize, usize)>, // (key, value, left, right, rank)
+}
+impl<T> PersistentLeftistHeap<T>
+where
+    T: Clone + Ord,
+{
+    const NIL: usize = !0;
+    fn new() -> Self {
+        Self { nodes: vec![] }
+    }
+    fn rank(&self, a: usize) -> usize {
+        if a == Self::NIL {
+            0
+        } else {
+            self.nodes[a].4
+        }
+    }
+    fn singleton(&mut self, key: T, value: usize) -> usize {
+        self.nodes.push((key, value, Self::NIL, Self::NIL, 1));
+        self.nodes.len() - 1
+    }
+    fn merge(&mut self, a: usize, b: usize) -> usize {
+        if a == Self::NIL {
+            return b;
+        }
+        if b == Self::NIL {
+            return a;
+        }
+        let (a, b) = if self.nodes[a].0 <= self.nodes[b].0 {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let mut node = self.nodes[a].clone();
+        node.3 = self.merge(node.3, b);
+        if self.rank(node.2) < self.rank(node.3) {
+            std::mem::swap(&mut node.2, &mut node.3);
+        }
+        node.4 = self.rank(node.3) + 1;
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+}
+
+impl DirectedSparseGraph {
+    /// k shortest walks from `s` to `t` with non-negative weights (Eppstein)
+    ///
+    /// Return at most `k` lengths in non-decreasing order.
+    /// Sidetrack costs are differences of distances, so weights form a [`Group`]
+    /// instead of a [`ShortestPathSemiRing`].
+    pub fn k_shortest_walks<G, F>(&self, s: usize, t: usize, k: usize, weight: F) -> Vec<G::T>
+    where
+        G: Group,
+        G::T: Ord,
+        F: Fn(usize) -> G::T,
+    {
+        let n = self.vertices_size();
+        let rgraph =
+            DirectedSparseGraph::from_edges(n, self.edges.iter().map(|&(u, v)| (v, u)).collect());
+        let (dist, prev) =
+            rgraph.dijkstra_ss_with_prev::<OptionSp<G>, _>(t, &|eid| Some(weight(eid)));
+        let mut res = Vec::with_capacity(k);
+        let ds = match &dist[s] {
+            Some(d) if k > 0 => d.clone(),
+            _ => return res,
+        };
+        // shortest path tree toward `t`
+        let mut tree_edge = vec![!0usize; n];
+        let mut children = vec![vec![]; n];
+        for v in self.vertices() {
+            if let Some(p) = prev[v] {
+                tree_edge[v] = self
+                    .adjacencies(v)
+                    .find(|a| {
+                        a.to == p
+                            && dist[v].as_ref()
+                                == Some(&G::operate(&weight(a.id), dist[p].as_ref().unwrap()))
+                    })
+                    .unwrap()
+                    .id;
+                children[p].push(v);
+            }
+        }
+        let mut heap = PersistentLeftistHeap::new();
+        let mut root = vec![PersistentLeftistHeap::<G::T>::NIL; n];
+        let mut stack = vec![t];
+        while let Some(v) = stack.pop() {
+            let mut h = if v == t {
+                PersistentLeftistHeap::<G::T>::NIL
+            } else {
+                root[self.edges[tree_edge[v]].1]
+            };
+            let dv = dist[v].as_ref().unwrap();
+            for a in self.adjacencies(v) {
+                if a.id == tree_edge[v] {
+                    continue;
+                }
+                if let Some(dt) = &dist[a.to] {
+                    // sidetrack cost: w + d(to) - d(v)
+                    let delta = G::rinv_operate(&G::operate(&weight(a.id), dt), dv);
+                    let node = heap.singleton(delta, a.id);
+                    h = heap.merge(h, node);
+                }
+            }
+            root[v] = h;
+            stack.extend(children[v].iter().cloned());
+        }
+        res.push(ds.clone());
+        let mut pq = BinaryHeap::new();
+        if root[s] != PersistentLeftistHeap::<G::T>::NIL {
+            let r = root[s];
+            pq.push(Reverse((G::operate(&ds, &heap.nodes[r].0), r)));
+        }
+        while res.len() < k {
+            let Reverse((c, node)) = match pq.pop() {
+                Some(x) => x,
+                None => break,
+            };
+            res.push(c.clone());
+            let (key, eid, left, right, _) = heap.nodes[node].clone();
+            let base = G::rinv_operate(&c, &key);
+            for ch in [left, right].iter().cloned() {
+                if ch != PersistentLeftistHeap::<G::T>::NIL {
+                    pq.push(Reverse((G::operate(&base, &heap.nodes[ch].0), ch)));
+                }
+            }
+            let r = root[self.edges[eid].1];
+            if r != PersistentLeftistHeap::<G::T>::NIL {
+                pq.push(Reverse((G::operate(&c, &heap.nodes[r].0), r)));
+            }
+        }
+        res
+    }
+
+    /// shortest path from `s` to `t` avoiding banned vertices and edges: (length, edge ids)
+    fn restricted_dijkstra<S, F>(
+        &self,
+        s: usize,
+        t: usize,
+        weight: &F,
+        banned_vertex: &[bool],
+        banned_edge: &[bool],
+    ) -> Option<(S::T, Vec<usize>)>
+    where
+        S: ShortestPathSemiRing,
+        F: Fn(usize) -> S::T,
+    {
+        let mut dist = vec![S::inf(); self.vertices_size()];
+        let mut prev = vec![!0usize; self.vertices_size()];
+        let mut heap = BinaryHeap::new();
+        dist[s] = S::source();
+        heap.push(Reverse((S::source(), s)));
+        while let Some(Reverse((d, u))) = heap.pop() {
+            if dist[u] != d {
+                continue;
+            }
+            if u == t {
+                break;
+            }
+            for a in self.adjacencies(u) {
+                if banned_vertex[a.to] || banned_edge[a.id] {
+                    continue;
+                }
+                let nd = S::mul(&d, &weight(a.id));
+                if S::add_assign(&mut dist[a.to], &nd) {
+                    prev[a.to] = a.id;
+                    heap.push(Reverse((nd, a.to)));
+                }
+            }
+        }
+        if dist[t] == S::inf() {
+            return None;
+        }
+        let d = dist[t].clone();
+        let mut path = vec![];
+        let mut v = t;
+        while v != s {
+            path.push(prev[v]);
+            v = self.edges[prev[v]].0;
+        }
+        path.reverse();
+        Some((d, path))
+    }
+
+    /// k shortest simple paths from `s` to `t` with non-negative weights (Yen)
+    ///
+    /// Return at most `k` pairs of (length, vertices) in non-decreasing order of length.
+    /// Parallel edges are distinguished.
+    pub fn k_shortest_simple_paths<S, F>(
+        &self,
+        s: usize,
+        t: usize,
+        k: usize,
+        weight: F,
+    ) -> Vec<(S::T, Vec<usize>)>
+    where
+        S: ShortestPathSemiRing,
+        F: Fn(usize) -> S::T,
+    {
+        let n = self.vertices_size();
+        let mut found: Vec<(S::T, Vec<usize>)> = vec![];
+        if k == 0 {
+            return vec![];
+        }
+        let mut banned_vertex = vec![false; n];
+        let mut banned_edge = vec![false; self.edges_size()];
+        let mut candidates = BTreeSet::new();
+        if let Some(p) =
+            self.restricted_dijkstra::<S, F>(s, t, &weight, &banned_vertex, &banned_edge)
+        {
+            candidates.insert(p);
+        }
+        while found.len() < k {
+            let (d, path) = match candidates.iter().next().cloned() {
+                Some(p) => p,
+                None => break,
+            };
+            candidates.remove(&(d.clone(), path.clone()));
+            found.push((d, path.clone()));
+            let mut root_cost = S::source();
+            let mut spur = s;
+            for i in 0..path.len() {
+                for (_, p) in found.iter() {
+                    if p.len() > i && p[..i] == path[..i] {
+                        banned_edge[p[i]] = true;
+                    }
+                }
+                if let Some((sd, sp)) =
+                    self.restricted_dijkstra::<S, F>(spur, t, &weight, &banned_vertex, &banned_edge)
+                {
+                    let mut np = path[..i].to_vec();
+                    np.extend(sp);
+                    candidates.insert((S::mul(&root_cost, &sd), np));
+                }
+                for (_, p) in found.iter() {
+                    if p.len() > i {
+                        banned_edge[p[i]] = false;
+                    }
+                }
+                banned_vertex[spur] = true;
+                root_cost = S::mul(&root_cost, &weight(path[i]));
+                spur = self.edges[path[i]].1;
+            }
+            for &eid in path.iter() {
+                banned_vertex[self.edges[eid].0] = false;
+            }
+        }
+        found
+            .into_iter()
+            .map(|(d, path)| {
+                let vs = Some(s)
+                    .into_iter()
+                    .chain(path.iter().map(|&eid| self.edges[eid].1))
+                    .collect();
+                (d, vs)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algebra::AdditiveOperation, graph::StandardSp, rand, tools::Xorshift};
+
+    #[test]
+    fn test_k_shortest_walks() {
+        const Q: usize = 300;
+        const N: usize = 6;
+        const K: usize = 20;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), m: (0..=N * 2), s: (0..n), t: (0..n), k: (0..=K));
+            rand!(rng, edges: [(0..n, 0..n); m], w: [1i64..=10; m]);
+            let graph = DirectedSparseGraph::from_edges(n, edges.clone());
+            let res = graph.k_shortest_walks::<AdditiveOperation<i64>, _>(s, t, k, |eid| w[eid]);
+            let mut reach = vec![false; n];
+            reach[t] = true;
+            for _ in 0..n {
+                for &(u, v) in edges.iter() {
+                    reach[u] |= reach[v];
+                }
+            }
+            let mut expected = vec![];
+            let mut pq = BinaryHeap::new();
+            pq.push(Reverse((0i64, s)));
+            while let Some(Reverse((d, u))) = pq.pop() {
+                if expected.len() >= k {
+                    break;
+                }
+                if u == t {
+                    expected.push(d);
+                }
+                for a in graph.adjacencies(u) {
+                    if reach[a.to] {
+                        pq.push(Reverse((d + w[a.id], a.to)));
+                    }
+                }
+            }
+            assert_eq!(res, expected);
+        }
+    }
+
+    #[test]
+    fn test_k_shortest_simple_paths() {
+        const Q: usize = 300;
+        const N: usize = 6;
+        const K: usize = 20;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), m: (0..=N * 2), s: (0..n), t: (0..n), k: (0..=K));
+            rand!(rng, edges: [(0..n, 0..n); m], w: [0u64..=10; m]);
+            let graph = DirectedSparseGraph::from_edges(n, edges.clone());
+            let res = graph.k_shortest_simple_paths::<StandardSp<AdditiveOperation<u64>>, _>(
+                s,
+                t,
+                k,
+                |eid| w[eid],
+            );
+            fn dfs(
+                graph: &DirectedSparseGraph,
+                w: &[u64],
+                u: usize,
+                t: usize,
+                d: u64,
+                vis: &mut Vec<bool>,
+                acc: &mut Vec<u64>,
+            ) {
+                if u == t {
+                    acc.push(d);
+                    return;
+                }
+                vis[u] = true;
+                for a in graph.adjacencies(u) {
+                    if !vis[a.to] {
+                        dfs(graph, w, a.to, t, d + w[a.id], vis, acc);
+                    }
+                }
+                vis[u] = false;
+            }
+            let mut expected = vec![];
+            dfs(&graph, &w, s, t, 0, &mut vec![false; n], &mut expected);
+            expected.sort_unstable();
+            expected.truncate(k);
+            assert_eq!(res.iter().map(|(d, _)| *d).collect::<Vec<_>>(), expected);
+            for (d, vs) in res.iter() {
+                assert_eq!(vs.first(), Some(&s));
+                assert_eq!(vs.last(), Some(&t));
+                let mut sorted = vs.clone();
+                sorted.sort_unstable();
+                sorted.dedup();
+                assert_eq!(sorted.len(), vs.len());
+                let min_cost: u64 = vs
+                    .windows(2)
+                    .map(|p| {
+                        graph
+                            .adjacencies(p[0])
+                            .filter(|a| a.to == p[1])
+                            .map(|a| w[a.id])
+                            .min()
+                            .unwrap()
+                    })
+                    .sum();
+                assert!(min_cost <= *d);
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
-use super::EdgeListGraph;
-use crate::algebra::Group;
+use super::{
+    AdjacencyIndex, AdjacencyIndexWithValue, AdjacencyView, EdgeListGraph, GraphBase, VertexMap,
+};
+use crate::algebra::{Group, Monoid};
 use crate::data_structure::{MergingUnionFind, UnionFind};
+use crate::tools::PartialIgnoredOrd;
 
 #[codesnip::entry("minimum_spanning_tree", include("EdgeListGraph", "UnionFind"))]
 impl EdgeListGraph {
@@ -25,12 +28,30 @@ impl EdgeListGraph {
     include("algebra", "EdgeListGraph", "MergingUnionFind")
 )]
 impl EdgeListGraph {
-    /// tarjan
+    /// tarjan: (total weight, parent vertices), `parent[root] = root`
     pub fn minimum_spanning_arborescence<G, F>(
         &self,
         root: usize,
         weight: F,
     ) -> Option<(G::T, Vec<usize>)>
+    where
+        G: Group,
+        G::T: Ord,
+        F: Fn(usize) -> G::T,
+    {
+        let (acc, tree) = self.minimum_spanning_arborescence_edges::<G, F>(root, weight)?;
+        let tree = self
+            .vertices()
+            .map(|v| if v == root { root } else { self[tree[v]].0 })
+            .collect();
+        Some((acc, tree))
+    }
+    /// tarjan: (total weight, edge ids entering each vertex), `!0` for `root`
+    pub fn minimum_spanning_arborescence_edges<G, F>(
+        &self,
+        root: usize,
+        weight: F,
+    ) -> Option<(G::T, Vec<usize>)>
     where
         G: Group,
         G::T: Ord,
@@ -106,12 +127,12 @@ impl EdgeListGraph {
                 state[u] = 2;
             }
         }
-        let mut tree = vec![root; self.vertices_size()];
+        let mut tree = vec![!0; self.vertices_size()];
         let mut used = vec![false; self.edges_size()];
         for eid in ord.into_iter().rev() {
             if !used[eid] {
-                let (u, v) = self[eid];
-                tree[v] = u;
+                let v = self[eid].1;
+                tree[v] = eid;
                 let mut x = leaf[v];
                 while x != eid {
                     used[x] = true;
@@ -122,3 +143,582 @@ impl EdgeListGraph {
         Some((acc, tree))
     }
 }
+
+#[codesnip::entry("boruvka", include("UnionFind"))]
+/// Borůvka's algorithm on an implicit graph: edges `(u, v, w)` of a minimum spanning forest
+///
+/// `f(comp)` returns the minimum edge `(w, to)` from each vertex `v` to a vertex `to` with
+/// `comp[to] != comp[v]`, or `None` if there is no such edge.
+pub fn boruvka<T, F>(n: usize, mut f: F) -> Vec<(usize, usize, T)>
+where
+    T: Clone + Ord,
+    F: FnMut(&[usize]) -> Vec<Option<(T, usize)>>,
+{
+    let mut uf = UnionFind::new(n);
+    let mut comp: Vec<usize> = (0..n).collect();
+    let mut res = Vec::with_capacity(n.saturating_sub(1));
+    loop {
+        let cand = f(&comp);
+        let mut best: Vec<Option<(T, usize, usize)>> = vec![None; n];
+        for (u, c) in cand.into_iter().enumerate() {
+            if let Some((w, v)) = c {
+                let e = (w, u.min(v), u.max(v));
+                let b = &mut best[comp[u]];
+                if !matches!(b, Some(x) if *x <= e) {
+                    *b = Some(e);
+                }
+            }
+        }
+        let mut updated = false;
+        for (w, u, v) in best.into_iter().flatten() {
+            if uf.unite(u, v) {
+                res.push((u, v, w));
+                updated = true;
+            }
+        }
+        if !updated {
+            break;
+        }
+        for (u, c) in comp.iter_mut().enumerate() {
+            *c = uf.find(u);
+        }
+    }
+    res
+}
+
+#[codesnip::entry("boruvka", include("EdgeListGraph"))]
+impl EdgeListGraph {
+    /// minimum spanning forest by Borůvka's algorithm, same as `minimum_spanning_tree`
+    pub fn minimum_spanning_tree_boruvka<T>(&self, weight: impl Fn(&usize) -> T) -> Vec<bool>
+    where
+        T: Clone + Ord,
+    {
+        let weight: Vec<_> = (0..self.edges_size()).map(|eid| weight(&eid)).collect();
+        let edges = boruvka(self.vertices_size(), |comp: &[usize]| {
+            let mut cand: Vec<Option<((T, usize), usize)>> = vec![None; self.vertices_size()];
+            for (eid, &(u, v)) in self.edges().enumerate() {
+                if comp[u] == comp[v] {
+                    continue;
+                }
+                let w = (weight[eid].clone(), eid);
+                for &(x, y) in [(u, v), (v, u)].iter() {
+                    if !matches!(&cand[x], Some((cw, _)) if cw <= &w) {
+                        cand[x] = Some((w.clone(), y));
+                    }
+                }
+            }
+            cand
+        });
+        let mut res = vec![false; self.edges_size()];
+        for (_, _, (_, eid)) in edges {
+            res[eid] = true;
+        }
+        res
+    }
+}
+
+#[codesnip::entry("prim", include("algebra", "GraphBase", "PartialIgnoredOrd"))]
+pub trait PrimExt<'g>: GraphBase<'g> {
+    /// minimum spanning tree of the component of `root` on an undirected graph:
+    /// (total weight, parent vertices), `None` for `root` and unreachable vertices
+    fn prim<'a, G, M>(
+        &'g self,
+        root: Self::VIndex,
+        weight: &'a M,
+    ) -> (
+        G::T,
+        <Self as VertexMap<'g, Option<<Self as GraphBase<'g>>::VIndex>>>::Vmap,
+    )
+    where
+        Self: VertexMap<'g, Option<<Self as GraphBase<'g>>::VIndex>>
+            + VertexMap<'g, bool>
+            + AdjacencyView<'g, 'a, M, G::T>,
+        G: Monoid,
+        G::T: Ord,
+    {
+        use std::{cmp::Reverse, collections::BinaryHeap};
+        let mut parent =
+            <Self as VertexMap<'g, Option<Self::VIndex>>>::construct_vmap(self, || None);
+        let mut used = <Self as VertexMap<'g, bool>>::construct_vmap(self, || false);
+        let mut acc = G::unit();
+        let mut heap = BinaryHeap::new();
+        heap.push(PartialIgnoredOrd(Reverse(G::unit()), (root, None)));
+        while let Some(PartialIgnoredOrd(Reverse(w), (u, p))) = heap.pop() {
+            if *<Self as VertexMap<'g, bool>>::vmap_get(self, &used, u) {
+                continue;
+            }
+            *<Self as VertexMap<'g, bool>>::vmap_get_mut(self, &mut used, u) = true;
+            *<Self as VertexMap<'g, Option<Self::VIndex>>>::vmap_get_mut(self, &mut parent, u) = p;
+            acc = G::operate(&acc, &w);
+            for a in self.aviews(weight, u) {
+                let v = a.vindex();
+                if !*<Self as VertexMap<'g, bool>>::vmap_get(self, &used, v) {
+                    heap.push(PartialIgnoredOrd(Reverse(a.avalue()), (v, Some(u))));
+                }
+            }
+        }
+        (acc, parent)
+    }
+}
+#[codesnip::entry("prim")]
+impl<'g, G> PrimExt<'g> for G where G: GraphBase<'g> {}
+
+#[codesnip::entry("manhattan_minimum_spanning_tree", include("UnionFind"))]
+/// minimum spanning tree of points with Manhattan distance: (total weight, edges)
+pub fn manhattan_minimum_spanning_tree(points: &[(i64, i64)]) -> (i64, Vec<(usize, usize)>) {
+    use std::collections::BTreeMap;
+    let n = points.len();
+    let mut ps = points.to_vec();
+    let mut idx: Vec<usize> = (0..n).collect();
+    let mut edges = vec![];
+    for k in 0..4 {
+        idx.sort_by_key(|&i| ps[i].0 + ps[i].1);
+        let mut sweep = BTreeMap::<i64, usize>::new();
+        for &i in idx.iter() {
+            let (x, y) = ps[i];
+            while let Some((&key, &j)) = sweep.range(-y..).next() {
+                let (dx, dy) = (x - ps[j].0, y - ps[j].1);
+                if dy > dx {
+                    break;
+                }
+                edges.push((dx + dy, i, j));
+                sweep.remove(&key);
+            }
+            sweep.insert(-y, i);
+        }
+        for p in ps.iter_mut() {
+            if k % 2 == 0 {
+                *p = (p.1, p.0);
+            } else {
+                p.0 = -p.0;
+            }
+        }
+    }
+    edges.sort_unstable();
+    let mut uf = UnionFind::new(n);
+    let mut acc = 0;
+    let mut res = Vec::with_capacity(n.saturating_sub(1));
+    for (w, u, v) in edges {
+        if uf.unite(u, v) {
+            acc += w;
+            res.push((u, v));
+        }
+    }
+    (acc, res)
+}
+
+#[codesnip::entry(
+    "second_minimum_spanning_tree",
+    include("algebra", "minimum_spanning_tree")
+)]
+impl EdgeListGraph {
+    /// minimum spanning forest among those different from `minimum_spanning_tree` as edge sets
+    ///
+    /// Return `None` if the minimum spanning forest is the only spanning forest.
+    pub fn second_minimum_spanning_tree<G, F>(&self, weight: F) -> Option<(G::T, Vec<bool>)>
+    where
+        G: Group,
+        G::T: Ord,
+        F: Fn(usize) -> G::T,
+    {
+        let n = self.vertices_size();
+        let w: Vec<_> = (0..self.edges_size()).map(&weight).collect();
+        let mut used = self.minimum_spanning_tree(|&eid| w[eid].clone());
+        let mut total = G::unit();
+        let mut adj = vec![vec![]; n];
+        for (eid, &(u, v)) in self.edges().enumerate() {
+            if used[eid] {
+                total = G::operate(&total, &w[eid]);
+                adj[u].push((v, eid));
+                adj[v].push((u, eid));
+            }
+        }
+        // up[k][v]: (2^k-th ancestor, heaviest edge id on the way)
+        let mut depth = vec![!0usize; n];
+        let mut up = vec![vec![(!0usize, !0usize); n]];
+        for r in self.vertices() {
+            if depth[r] != !0 {
+                continue;
+            }
+            depth[r] = 0;
+            up[0][r] = (r, !0);
+            let mut stack = vec![r];
+            while let Some(u) = stack.pop() {
+                for &(v, eid) in adj[u].iter() {
+                    if depth[v] == !0 {
+                        depth[v] = depth[u] + 1;
+                        up[0][v] = (u, eid);
+                        stack.push(v);
+                    }
+                }
+            }
+        }
+        let heavier = |a: usize, b: usize| {
+            if a == !0 || (b != !0 && w[a] < w[b]) {
+                b
+            } else {
+                a
+            }
+        };
+        while 1 << up.len() < n {
+            let prev = up.last().unwrap();
+            let next = (0..n)
+                .map(|v| {
+                    let (p, e1) = prev[v];
+                    let (pp, e2) = prev[p];
+                    (pp, heavier(e1, e2))
+                })
+                .collect();
+            up.push(next);
+        }
+        let path_max = |mut u: usize, mut v: usize| {
+            let mut res = !0usize;
+            if depth[u] < depth[v] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            for (k, up) in up.iter().enumerate().rev() {
+                if (depth[u] - depth[v]) >> k & 1 == 1 {
+                    res = heavier(res, up[u].1);
+                    u = up[u].0;
+                }
+            }
+            if u == v {
+                return res;
+            }
+            for up in up.iter().rev() {
+                if up[u].0 != up[v].0 {
+                    res = heavier(res, heavier(up[u].1, up[v].1));
+                    u = up[u].0;
+                    v = up[v].0;
+                }
+            }
+            heavier(res, heavier(up[0][u].1, up[0][v].1))
+        };
+        let mut best: Option<(G::T, usize, usize)> = None;
+        for (eid, &(u, v)) in self.edges().enumerate() {
+            if used[eid] || u == v {
+                continue;
+            }
+            let rem = path_max(u, v);
+            let cost = G::operate(&G::rinv_operate(&total, &w[rem]), &w[eid]);
+            if !matches!(&best, Some((c, _, _)) if c <= &cost) {
+                best = Some((cost, rem, eid));
+            }
+        }
+        let (cost, rem, add) = best?;
+        used[rem] = false;
+        used[add] = true;
+        Some((cost, used))
+    }
+}
+
+#[codesnip::entry(
+    "minimum_spanning_tree_offline",
+    include("algebra", "EdgeListGraph", "UnionFind")
+)]
+impl EdgeListGraph {
+    /// total weight of the minimum spanning forest after each update `weight[eid] = w`
+    ///
+    /// O((m + q log q) log q) by divide and conquer with contraction and reduction
+    pub fn minimum_spanning_tree_offline<G>(
+        &self,
+        mut weight: Vec<G::T>,
+        queries: &[(usize, G::T)],
+    ) -> Vec<G::T>
+    where
+        G: Monoid,
+        G::T: Ord,
+    {
+        let edges: Vec<_> = self
+            .edges()
+            .enumerate()
+            .filter(|(_, &(u, v))| u != v)
+            .map(|(eid, &(u, v))| (u, v, eid))
+            .collect();
+        let mut res = Vec::with_capacity(queries.len());
+        if !queries.is_empty() {
+            let mut modified = vec![false; self.edges_size()];
+            offline_mst_rec::<G>(
+                self.vertices_size(),
+                edges,
+                queries,
+                G::unit(),
+                &mut weight,
+                &mut modified,
+                &mut res,
+            );
+        }
+        res
+    }
+}
+
+#[codesnip::entry("minimum_spanning_tree_offline")]
+fn offline_mst_rec<G>(
+    n: usize,
+    mut edges: Vec<(usize, usize, usize)>,
+    queries: &[(usize, G::T)],
+    mut acc: G::T,
+    weight: &mut [G::T],
+    modified: &mut [bool],
+    res: &mut Vec<G::T>,
+) where
+    G: Monoid,
+    G::T: Ord,
+{
+    if queries.len() == 1 {
+        let (eid, w) = &queries[0];
+        weight[*eid] = w.clone();
+        edges.sort_by(|a, b| weight[a.2].cmp(&weight[b.2]));
+        let mut uf = UnionFind::new(n);
+        for (u, v, eid) in edges {
+            if uf.unite(u, v) {
+                acc = G::operate(&acc, &weight[eid]);
+            }
+        }
+        res.push(acc);
+        return;
+    }
+    for &(eid, _) in queries.iter() {
+        modified[eid] = true;
+    }
+    let (mods, mut rest): (Vec<_>, Vec<_>) = edges.into_iter().partition(|e| modified[e.2]);
+    for &(eid, _) in queries.iter() {
+        modified[eid] = false;
+    }
+    rest.sort_by(|a, b| weight[a.2].cmp(&weight[b.2]));
+    // contraction: edges chosen even if all modified edges are chosen first are always used
+    let mut uf = UnionFind::new(n);
+    let mut forced = UnionFind::new(n);
+    for &(u, v, _) in mods.iter() {
+        uf.unite(u, v);
+    }
+    rest.retain(|&(u, v, eid)| {
+        if uf.unite(u, v) {
+            forced.unite(u, v);
+            acc = G::operate(&acc, &weight[eid]);
+            false
+        } else {
+            true
+        }
+    });
+    let mut id = vec![!0usize; n];
+    let mut m = 0;
+    for v in 0..n {
+        let r = forced.find(v);
+        if id[r] == !0 {
+            id[r] = m;
+            m += 1;
+        }
+    }
+    let mut relabel = |edges: Vec<(usize, usize, usize)>| -> Vec<(usize, usize, usize)> {
+        edges
+            .into_iter()
+            .map(|(u, v, eid)| (id[forced.find(u)], id[forced.find(v)], eid))
+            .filter(|&(u, v, _)| u != v)
+            .collect()
+    };
+    let mut edges = relabel(mods);
+    let rest = relabel(rest);
+    // reduction: edges not chosen even if all modified edges are chosen last are never used
+    let mut uf = UnionFind::new(m);
+    edges.extend(rest.into_iter().filter(|&(u, v, _)| uf.unite(u, v)));
+    let mid = queries.len() / 2;
+    offline_mst_rec::<G>(
+        m,
+        edges.clone(),
+        &queries[..mid],
+        acc.clone(),
+        weight,
+        modified,
+        res,
+    );
+    offline_mst_rec::<G>(m, edges, &queries[mid..], acc, weight, modified, res);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algebra::AdditiveOperation,
+        graph::{UndirectedSparseGraph, UsizeGraph},
+        rand,
+        tools::Xorshift,
+    };
+
+    fn kruskal_weight(n: usize, edges: &[(usize, usize)], w: &[i64]) -> i64 {
+        let graph = EdgeListGraph::from_edges(n, edges.to_vec());
+        let used = graph.minimum_spanning_tree(|&eid| w[eid]);
+        (0..edges.len())
+            .filter(|&eid| used[eid])
+            .map(|eid| w[eid])
+            .sum()
+    }
+
+    #[test]
+    fn test_boruvka_prim() {
+        const Q: usize = 500;
+        const N: usize = 10;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), m: (0..=N * 2));
+            let mut edges: Vec<_> = (1..n).map(|v| (rng.gen(0..v), v)).collect();
+            rand!(rng, extra: [(0..n, 0..n); m], w: [0i64..=10; n - 1 + m]);
+            edges.extend(extra);
+            let graph = EdgeListGraph::from_edges(n, edges.clone());
+            let kruskal = graph.minimum_spanning_tree(|&eid| w[eid]);
+            let used = graph.minimum_spanning_tree_boruvka(|&eid| w[eid]);
+            assert_eq!(kruskal, used);
+            let expected = kruskal_weight(n, &edges, &w);
+
+            let sgraph = UndirectedSparseGraph::from_edges(n, edges.clone());
+            let (cost, parent) = sgraph.prim::<AdditiveOperation<i64>, _>(0, &|eid| w[eid]);
+            assert_eq!(cost, expected);
+            assert_eq!(parent[0], None);
+            assert!(parent[1..].iter().all(|p| p.is_some()));
+
+            let dist =
+                |u: usize, v: usize| ((u * 7 + v * 7) % 11) as i64 + (u as i64 - v as i64).abs();
+            let ugraph = UsizeGraph::new(n, |u| {
+                (0..n)
+                    .filter(move |&v| v != u)
+                    .map(move |v| (v, dist(u, v)))
+            });
+            let (cost, _) = ugraph.prim::<AdditiveOperation<i64>, _>(n - 1, &|d| d);
+            let complete: Vec<_> = (0..n).flat_map(|u| (0..u).map(move |v| (u, v))).collect();
+            let cw: Vec<_> = complete.iter().map(|&(u, v)| dist(u, v)).collect();
+            assert_eq!(cost, kruskal_weight(n, &complete, &cw));
+            let forest = boruvka(n, |comp: &[usize]| {
+                (0..n)
+                    .map(|u| {
+                        (0..n)
+                            .filter(|&v| comp[u] != comp[v])
+                            .map(|v| (dist(u, v), v))
+                            .min()
+                    })
+                    .collect()
+            });
+            assert_eq!(forest.len(), n - 1);
+            assert_eq!(forest.iter().map(|e| e.2).sum::<i64>(), cost);
+        }
+    }
+
+    #[test]
+    fn test_minimum_spanning_arborescence_edges() {
+        const Q: usize = 500;
+        const N: usize = 10;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), m: (0..=N * 3), root: (0..n));
+            rand!(rng, edges: [(0..n, 0..n); m], w: [0i64..=10; m]);
+            let graph = EdgeListGraph::from_edges(n, edges);
+            let res =
+                graph.minimum_spanning_arborescence::<AdditiveOperation<_>, _>(root, |eid| w[eid]);
+            let res_edges = graph
+                .minimum_spanning_arborescence_edges::<AdditiveOperation<_>, _>(root, |eid| w[eid]);
+            assert_eq!(res.is_some(), res_edges.is_some());
+            if let (Some((c1, parent)), Some((c2, tree))) = (res, res_edges) {
+                assert_eq!(c1, c2);
+                let mut sum = 0;
+                for v in 0..n {
+                    if v == root {
+                        assert_eq!(tree[v], !0);
+                        continue;
+                    }
+                    assert_eq!(graph[tree[v]], (parent[v], v));
+                    sum += w[tree[v]];
+                }
+                assert_eq!(sum, c2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_manhattan_minimum_spanning_tree() {
+        const Q: usize = 300;
+        const N: usize = 30;
+        const A: i64 = 20;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (0..=N), points: [(-A..=A, -A..=A); n]);
+            let (cost, edges) = manhattan_minimum_spanning_tree(&points);
+            let d = |u: usize, v: usize| {
+                (points[u].0 - points[v].0).abs() + (points[u].1 - points[v].1).abs()
+            };
+            let complete: Vec<_> = (0..n).flat_map(|u| (0..u).map(move |v| (u, v))).collect();
+            let cw: Vec<_> = complete.iter().map(|&(u, v)| d(u, v)).collect();
+            assert_eq!(cost, kruskal_weight(n, &complete, &cw));
+            assert_eq!(edges.len(), n.saturating_sub(1));
+            assert_eq!(edges.iter().map(|&(u, v)| d(u, v)).sum::<i64>(), cost);
+            let mut uf = UnionFind::new(n);
+            assert!(edges.iter().all(|&(u, v)| uf.unite(u, v)));
+        }
+    }
+
+    #[test]
+    fn test_second_minimum_spanning_tree() {
+        const Q: usize = 500;
+        const N: usize = 6;
+        const M: usize = 9;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), m: (0..=M));
+            rand!(rng, edges: [(0..n, 0..n); m], w: [0i64..=5; m]);
+            let graph = EdgeListGraph::from_edges(n, edges.clone());
+            let mst = graph.minimum_spanning_tree(|&eid| w[eid]);
+            let size = mst.iter().filter(|&&b| b).count();
+            let mut expected = None;
+            for s in 0usize..1 << m {
+                if s.count_ones() as usize != size {
+                    continue;
+                }
+                let used: Vec<bool> = (0..m).map(|i| s >> i & 1 == 1).collect();
+                let mut uf = UnionFind::new(n);
+                if used != mst
+                    && (0..m)
+                        .filter(|&i| used[i])
+                        .all(|i| uf.unite(edges[i].0, edges[i].1))
+                {
+                    let c: i64 = (0..m).filter(|&i| used[i]).map(|i| w[i]).sum();
+                    if !matches!(expected, Some(e) if e <= c) {
+                        expected = Some(c);
+                    }
+                }
+            }
+            let res = graph.second_minimum_spanning_tree::<AdditiveOperation<_>, _>(|eid| w[eid]);
+            assert_eq!(res.as_ref().map(|r| r.0), expected);
+            if let Some((c, used)) = res {
+                assert_ne!(used, mst);
+                assert_eq!((0..m).filter(|&i| used[i]).count(), size);
+                assert_eq!((0..m).filter(|&i| used[i]).map(|i| w[i]).sum::<i64>(), c);
+                let mut uf = UnionFind::new(n);
+                assert!((0..m)
+                    .filter(|&i| used[i])
+                    .all(|i| uf.unite(edges[i].0, edges[i].1)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree_offline() {
+        const Q: usize = 200;
+        const N: usize = 10;
+        const M: usize = 20;
+        const K: usize = 30;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), m: (1..=M), k: (0..=K));
+            rand!(rng, edges: [(0..n, 0..n); m], mut w: [0i64..=10; m], queries: [(0..m, 0i64..=10); k]);
+            let graph = EdgeListGraph::from_edges(n, edges.clone());
+            let res =
+                graph.minimum_spanning_tree_offline::<AdditiveOperation<_>>(w.clone(), &queries);
+            let expected: Vec<_> = queries
+                .iter()
+                .map(|&(eid, x)| {
+                    w[eid] = x;
+                    kruskal_weight(n, &edges, &w)
+                })
+                .collect();
+            assert_eq!(res, expected);
+        }
+    }
+}
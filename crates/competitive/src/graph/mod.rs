@@ -1,7 +1,7 @@
 //! graph structures and algorithms
 
 use crate::{
-    algebra::{Monoid, SemiRing},
+    algebra::{Group, Monoid, SemiRing},
     num::Bounded,
     tools::{IterScan, MarkedIterScan, PartialIgnoredOrd},
 };
@@ -12,10 +12,18 @@ pub use self::adjacency_list::{AdjacencyListGraph, AdjacencyListGraphScanner};
 pub use self::bipartite_matching::BipartiteMatching;
 #[codesnip::entry("ClosureGraph")]
 pub use self::closure::{ClosureGraph, UsizeGraph};
+#[codesnip::entry("DominatorTree")]
+pub use self::dominator_tree::DominatorTree;
 #[codesnip::entry("dulmage_mendelsohn_decomposition")]
 pub use self::dulmage_mendelsohn_decomposition::dulmage_mendelsohn_decomposition;
 #[codesnip::entry("EdgeListGraph")]
 pub use self::edge_list::{EdgeListGraph, EdgeListGraphScanner};
+#[codesnip::entry("eulerian_trail")]
+pub use self::eulerian_trail::{de_bruijn_sequence, EulerianTrailError};
+#[codesnip::entry("FunctionalGraph")]
+pub use self::functional_graph::{FunctionalGraph, FunctionalGraphDoubling};
+#[codesnip::entry("graph_generator")]
+pub use self::generator::*;
 #[codesnip::entry("GraphBase")]
 pub use self::graph_base::*;
 #[codesnip::entry("GridGraph")]
@@ -26,6 +34,7 @@ pub use self::low_link::LowLink;
 pub use self::maximum_flow::{Dinic, DinicBuilder};
 #[codesnip::entry("PrimalDual")]
 pub use self::minimum_cost_flow::{PrimalDual, PrimalDualBuilder};
+pub use self::minimum_spanning_tree::{boruvka, manhattan_minimum_spanning_tree, PrimExt};
 #[codesnip::entry("ProjectSelectionProblem")]
 pub use self::project_selection_problem::ProjectSelectionProblem;
 #[codesnip::entry("shortest_path")]
@@ -43,6 +52,10 @@ mod adjacency_list;
 mod bipartite_matching;
 #[cfg_attr(nightly, codesnip::entry("ClosureGraph", include("GraphBase")))]
 mod closure;
+#[cfg_attr(nightly, codesnip::entry("cycle", include("SparseGraph")))]
+mod cycle;
+#[cfg_attr(nightly, codesnip::entry("DominatorTree", include("SparseGraph")))]
+mod dominator_tree;
 #[cfg_attr(
     nightly,
     codesnip::entry(
@@ -53,12 +66,29 @@ mod closure;
 mod dulmage_mendelsohn_decomposition;
 #[cfg_attr(nightly, codesnip::entry("EdgeListGraph", include("scanner")))]
 mod edge_list;
+#[cfg_attr(nightly, codesnip::entry("eulerian_trail", include("SparseGraph")))]
+mod eulerian_trail;
+#[cfg_attr(
+    nightly,
+    codesnip::entry("FunctionalGraph", include("algebra", "ClosureGraph", "LevelAncestor"))
+)]
+mod functional_graph;
+#[cfg_attr(
+    nightly,
+    codesnip::entry("graph_generator", include("SparseGraph", "random_generator"))
+)]
+mod generator;
 #[cfg_attr(nightly, codesnip::entry("GraphBase"))]
 mod graph_base;
 #[cfg_attr(nightly, codesnip::entry("graphvis", include("SparseGraph")))]
 mod graphvis;
 #[cfg_attr(nightly, codesnip::entry("GridGraph", include("GraphBase")))]
 mod grid;
+#[cfg_attr(
+    nightly,
+    codesnip::entry("k_shortest_path", include("shortest_path", "SparseGraph"))
+)]
+mod k_shortest_path;
 #[cfg_attr(nightly, codesnip::entry("LowLink", include("SparseGraph")))]
 mod low_link;
 #[cfg_attr(nightly, codesnip::entry("Dinic", include("SparseGraph")))]
@@ -110,166 +110,314 @@ where
     }
 }
 
+type Vmap<'g, G, T> = <G as VertexMap<'g, T>>::Vmap;
+type Prev<'g, G> = Option<<G as GraphBase<'g>>::VIndex>;
+type PrevMap<'g, G> = Vmap<'g, G, Prev<'g, G>>;
+type AllPairs<'g, G, T> = Vmap<'g, G, Vmap<'g, G, T>>;
+type WithPrev<'g, G, T> = (Vmap<'g, G, T>, PrevMap<'g, G>);
+type AllPairsWithPrev<'g, G, T> = (AllPairs<'g, G, T>, AllPairs<'g, G, Prev<'g, G>>);
+
 pub trait ShortestPathExt<'g>: GraphBase<'g> {
     fn bfs_distance_ss<'a, S, M>(
         &'g self,
         source: Self::VIndex,
         weight: &'a M,
-    ) -> <Self as VertexMap<'g, S::T>>::Vmap
+    ) -> Vmap<'g, Self, S::T>
     where
         Self: VertexMap<'g, S::T> + AdjacencyView<'g, 'a, M, S::T>,
         S: ShortestPathSemiRing,
     {
         self.bfs_distance_ms::<S, M, _>(once(source), weight)
     }
-    fn bfs_distance_ms<'a, S, M, I>(
+    fn bfs_distance_ms<'a, S, M, I>(&'g self, sources: I, weight: &'a M) -> Vmap<'g, Self, S::T>
+    where
+        Self: VertexMap<'g, S::T> + AdjacencyView<'g, 'a, M, S::T>,
+        S: ShortestPathSemiRing,
+        I: IntoIterator<Item = Self::VIndex>,
+    {
+        bfs_distance_impl::<Self, S, M, _, _>(self, sources, weight, |_, _| {})
+    }
+    fn dijkstra_ss<'a, S, M>(&'g self, source: Self::VIndex, weight: &'a M) -> Vmap<'g, Self, S::T>
+    where
+        Self: VertexMap<'g, S::T> + AdjacencyView<'g, 'a, M, S::T>,
+        S: ShortestPathSemiRing,
+    {
+        self.dijkstra_ms::<S, M, _>(once(source), weight)
+    }
+    fn dijkstra_ms<'a, S, M, I>(&'g self, sources: I, weight: &'a M) -> Vmap<'g, Self, S::T>
+    where
+        Self: VertexMap<'g, S::T> + AdjacencyView<'g, 'a, M, S::T>,
+        S: ShortestPathSemiRing,
+        I: IntoIterator<Item = Self::VIndex>,
+    {
+        dijkstra_impl::<Self, S, M, _, _>(self, sources, weight, |_, _| {})
+    }
+    fn bellman_ford_ss<'a, S, M>(
+        &'g self,
+        source: Self::VIndex,
+        weight: &'a M,
+        check: bool,
+    ) -> Option<Vmap<'g, Self, S::T>>
+    where
+        Self: Vertices<'g> + VertexMap<'g, S::T> + AdjacencyView<'g, 'a, M, S::T> + VertexSize<'g>,
+        S: ShortestPathSemiRing,
+    {
+        self.bellman_ford_ms::<S, M, _>(once(source), weight, check)
+    }
+    fn bellman_ford_ms<'a, S, M, I>(
         &'g self,
         sources: I,
         weight: &'a M,
-    ) -> <Self as VertexMap<'g, S::T>>::Vmap
+        check: bool,
+    ) -> Option<Vmap<'g, Self, S::T>>
     where
-        Self: VertexMap<'g, S::T> + AdjacencyView<'g, 'a, M, S::T>,
+        Self: Vertices<'g> + VertexMap<'g, S::T> + AdjacencyView<'g, 'a, M, S::T> + VertexSize<'g>,
         S: ShortestPathSemiRing,
         I: IntoIterator<Item = Self::VIndex>,
     {
-        let mut cost = self.construct_vmap(S::inf);
-        let mut deq = VecDeque::new();
-        for source in sources.into_iter() {
-            *self.vmap_get_mut(&mut cost, source) = S::source();
-            deq.push_back(source);
-        }
-        let zero = S::source();
-        while let Some(u) = deq.pop_front() {
-            for a in self.aviews(weight, u) {
-                let v = a.vindex();
-                let w = a.avalue();
-                let nd = S::mul(self.vmap_get(&cost, u), &w);
-                if S::add_assign(self.vmap_get_mut(&mut cost, v), &nd) {
-                    if w == zero {
-                        deq.push_front(v);
-                    } else {
-                        deq.push_back(v);
-                    }
-                }
-            }
+        match bellman_ford_impl::<Self, S, M, _, _>(self, sources, weight, check, |_, _| {}) {
+            (cost, None) => Some(cost),
+            (_, Some(_)) => None,
         }
-        cost
     }
-    fn dijkstra_ss<'a, S, M>(
+    /// Return `None` if a negative cycle reachable from the source exists.
+    fn spfa_ss<'a, S, M>(
         &'g self,
         source: Self::VIndex,
         weight: &'a M,
-    ) -> <Self as VertexMap<'g, S::T>>::Vmap
+    ) -> Option<Vmap<'g, Self, S::T>>
     where
-        Self: VertexMap<'g, S::T> + AdjacencyView<'g, 'a, M, S::T>,
+        Self: VertexMap<'g, S::T>
+            + VertexMap<'g, usize>
+            + VertexMap<'g, bool>
+            + AdjacencyView<'g, 'a, M, S::T>
+            + VertexSize<'g>,
         S: ShortestPathSemiRing,
     {
-        self.dijkstra_ms::<S, M, _>(once(source), weight)
+        self.spfa_ms::<S, M, _>(once(source), weight)
     }
-    fn dijkstra_ms<'a, S, M, I>(
+    /// Return `None` if a negative cycle reachable from the sources exists.
+    fn spfa_ms<'a, S, M, I>(&'g self, sources: I, weight: &'a M) -> Option<Vmap<'g, Self, S::T>>
+    where
+        Self: VertexMap<'g, S::T>
+            + VertexMap<'g, usize>
+            + VertexMap<'g, bool>
+            + AdjacencyView<'g, 'a, M, S::T>
+            + VertexSize<'g>,
+        S: ShortestPathSemiRing,
+        I: IntoIterator<Item = Self::VIndex>,
+    {
+        spfa_impl::<Self, S, M, _, _>(self, sources, weight, |_, _| {})
+    }
+    /// Return `None` if a negative cycle reachable from the source exists.
+    fn spfa_ss_with_prev<'a, S, M>(
+        &'g self,
+        source: Self::VIndex,
+        weight: &'a M,
+    ) -> Option<WithPrev<'g, Self, S::T>>
+    where
+        Self: VertexMap<'g, S::T>
+            + VertexMap<'g, usize>
+            + VertexMap<'g, bool>
+            + VertexMap<'g, Prev<'g, Self>>
+            + AdjacencyView<'g, 'a, M, S::T>
+            + VertexSize<'g>,
+        S: ShortestPathSemiRing,
+    {
+        self.spfa_ms_with_prev::<S, M, _>(once(source), weight)
+    }
+    /// Return `None` if a negative cycle reachable from the sources exists.
+    fn spfa_ms_with_prev<'a, S, M, I>(
         &'g self,
         sources: I,
         weight: &'a M,
-    ) -> <Self as VertexMap<'g, S::T>>::Vmap
+    ) -> Option<WithPrev<'g, Self, S::T>>
     where
-        Self: VertexMap<'g, S::T> + AdjacencyView<'g, 'a, M, S::T>,
+        Self: VertexMap<'g, S::T>
+            + VertexMap<'g, usize>
+            + VertexMap<'g, bool>
+            + VertexMap<'g, Prev<'g, Self>>
+            + AdjacencyView<'g, 'a, M, S::T>
+            + VertexSize<'g>,
         S: ShortestPathSemiRing,
         I: IntoIterator<Item = Self::VIndex>,
     {
-        let mut cost = self.construct_vmap(S::inf);
-        let mut heap = BinaryHeap::new();
-        for source in sources.into_iter() {
-            *self.vmap_get_mut(&mut cost, source) = S::source();
-            heap.push(PartialIgnoredOrd(Reverse(S::source()), source));
+        let mut prev = self.construct_vmap(|| None);
+        let cost = spfa_impl::<Self, S, M, _, _>(self, sources, weight, |u, v| {
+            *self.vmap_get_mut(&mut prev, v) = Some(u);
+        })?;
+        Some((cost, prev))
+    }
+    fn warshall_floyd_ap<'a, S, M>(&'g self, weight: &'a M) -> AllPairs<'g, Self, S::T>
+    where
+        Self: Vertices<'g>
+            + VertexMap<'g, S::T>
+            + VertexMap<'g, Vmap<'g, Self, S::T>>
+            + AdjacencyView<'g, 'a, M, S::T>,
+        Vmap<'g, Self, S::T>: Clone,
+        S: ShortestPathSemiRing,
+    {
+        let mut cost = self.construct_vmap(|| self.construct_vmap(S::inf));
+        for u in self.vertices() {
+            *self.vmap_get_mut(self.vmap_get_mut(&mut cost, u), u) = S::source();
         }
-        while let Some(PartialIgnoredOrd(Reverse(d), u)) = heap.pop() {
-            if self.vmap_get(&cost, u) != &d {
-                continue;
-            }
-            let d = self.vmap_get(&cost, u).clone();
+        for u in self.vertices() {
             for a in self.aviews(weight, u) {
-                let v = a.vindex();
-                let nd = S::mul(&d, &a.avalue());
-                if S::add_assign(self.vmap_get_mut(&mut cost, v), &nd) {
-                    heap.push(PartialIgnoredOrd(Reverse(nd), v));
+                S::add_assign(
+                    self.vmap_get_mut(self.vmap_get_mut(&mut cost, u), a.vindex()),
+                    &a.avalue(),
+                );
+            }
+        }
+        for k in self.vertices() {
+            for i in self.vertices() {
+                for j in self.vertices() {
+                    let d1 = self.vmap_get(self.vmap_get(&cost, i), k);
+                    let d2 = self.vmap_get(self.vmap_get(&cost, k), j);
+                    let nd = S::mul(d1, d2);
+                    S::add_assign(self.vmap_get_mut(self.vmap_get_mut(&mut cost, i), j), &nd);
                 }
             }
         }
         cost
     }
-    fn bellman_ford_ss<'a, S, M>(
+    fn bfs_distance_ss_with_prev<'a, S, M>(
         &'g self,
         source: Self::VIndex,
         weight: &'a M,
-        check: bool,
-    ) -> Option<<Self as VertexMap<'g, S::T>>::Vmap>
+    ) -> WithPrev<'g, Self, S::T>
     where
-        Self: Vertices<'g> + VertexMap<'g, S::T> + AdjacencyView<'g, 'a, M, S::T> + VertexSize<'g>,
+        Self: VertexMap<'g, S::T> + VertexMap<'g, Prev<'g, Self>> + AdjacencyView<'g, 'a, M, S::T>,
         S: ShortestPathSemiRing,
     {
-        self.bellman_ford_ms::<S, M, _>(once(source), weight, check)
+        self.bfs_distance_ms_with_prev::<S, M, _>(once(source), weight)
     }
-    fn bellman_ford_ms<'a, S, M, I>(
+    fn bfs_distance_ms_with_prev<'a, S, M, I>(
         &'g self,
         sources: I,
         weight: &'a M,
-        check: bool,
-    ) -> Option<<Self as VertexMap<'g, S::T>>::Vmap>
+    ) -> WithPrev<'g, Self, S::T>
     where
-        Self: Vertices<'g> + VertexMap<'g, S::T> + AdjacencyView<'g, 'a, M, S::T> + VertexSize<'g>,
+        Self: VertexMap<'g, S::T> + VertexMap<'g, Prev<'g, Self>> + AdjacencyView<'g, 'a, M, S::T>,
         S: ShortestPathSemiRing,
         I: IntoIterator<Item = Self::VIndex>,
     {
-        let mut cost = self.construct_vmap(S::inf);
-        for source in sources.into_iter() {
-            *self.vmap_get_mut(&mut cost, source) = S::source();
-        }
-        let vsize = self.vsize();
-        for _ in 1..vsize {
-            let mut updated = false;
-            for u in self.vertices() {
-                for a in self.aviews(weight, u) {
-                    let v = a.vindex();
-                    let nd = S::mul(self.vmap_get(&cost, u), &a.avalue());
-                    updated |= S::add_assign(self.vmap_get_mut(&mut cost, v), &nd);
-                }
-            }
-            if !updated {
-                break;
+        let mut prev = self.construct_vmap(|| None);
+        let cost = bfs_distance_impl::<Self, S, M, _, _>(self, sources, weight, |u, v| {
+            *self.vmap_get_mut(&mut prev, v) = Some(u);
+        });
+        (cost, prev)
+    }
+    fn dijkstra_ss_with_prev<'a, S, M>(
+        &'g self,
+        source: Self::VIndex,
+        weight: &'a M,
+    ) -> WithPrev<'g, Self, S::T>
+    where
+        Self: VertexMap<'g, S::T> + VertexMap<'g, Prev<'g, Self>> + AdjacencyView<'g, 'a, M, S::T>,
+        S: ShortestPathSemiRing,
+    {
+        self.dijkstra_ms_with_prev::<S, M, _>(once(source), weight)
+    }
+    fn dijkstra_ms_with_prev<'a, S, M, I>(
+        &'g self,
+        sources: I,
+        weight: &'a M,
+    ) -> WithPrev<'g, Self, S::T>
+    where
+        Self: VertexMap<'g, S::T> + VertexMap<'g, Prev<'g, Self>> + AdjacencyView<'g, 'a, M, S::T>,
+        S: ShortestPathSemiRing,
+        I: IntoIterator<Item = Self::VIndex>,
+    {
+        let mut prev = self.construct_vmap(|| None);
+        let cost = dijkstra_impl::<Self, S, M, _, _>(self, sources, weight, |u, v| {
+            *self.vmap_get_mut(&mut prev, v) = Some(u);
+        });
+        (cost, prev)
+    }
+    /// Return `Err(cycle)` if a negative cycle reachable from the source exists.
+    fn bellman_ford_ss_with_prev<'a, S, M>(
+        &'g self,
+        source: Self::VIndex,
+        weight: &'a M,
+    ) -> Result<WithPrev<'g, Self, S::T>, Vec<Self::VIndex>>
+    where
+        Self: Vertices<'g>
+            + VertexMap<'g, S::T>
+            + VertexMap<'g, Prev<'g, Self>>
+            + AdjacencyView<'g, 'a, M, S::T>
+            + VertexSize<'g>,
+        S: ShortestPathSemiRing,
+    {
+        self.bellman_ford_ms_with_prev::<S, M, _>(once(source), weight)
+    }
+    /// Return `Err(cycle)` if a negative cycle reachable from the sources exists.
+    fn bellman_ford_ms_with_prev<'a, S, M, I>(
+        &'g self,
+        sources: I,
+        weight: &'a M,
+    ) -> Result<WithPrev<'g, Self, S::T>, Vec<Self::VIndex>>
+    where
+        Self: Vertices<'g>
+            + VertexMap<'g, S::T>
+            + VertexMap<'g, Prev<'g, Self>>
+            + AdjacencyView<'g, 'a, M, S::T>
+            + VertexSize<'g>,
+        S: ShortestPathSemiRing,
+        I: IntoIterator<Item = Self::VIndex>,
+    {
+        let mut prev = self.construct_vmap(|| None);
+        let (cost, last) =
+            bellman_ford_impl::<Self, S, M, _, _>(self, sources, weight, true, |u, v| {
+                *self.vmap_get_mut(&mut prev, v) = Some(u);
+            });
+        if let Some(mut v) = last {
+            let p = |v| <Self as VertexMap<'g, Prev<'g, Self>>>::vmap_get(self, &prev, v).unwrap();
+            // walk back far enough to land on the cycle
+            for _ in 0..self.vsize() {
+                v = p(v);
             }
-        }
-        if check {
-            for u in self.vertices() {
-                for a in self.aviews(weight, u) {
-                    let v = a.vindex();
-                    let nd = S::mul(self.vmap_get(&cost, u), &a.avalue());
-                    if S::add_assign(self.vmap_get_mut(&mut cost, v), &nd) {
-                        return None;
-                    }
-                }
+            let mut cycle = vec![v];
+            let mut u = p(v);
+            while u != v {
+                cycle.push(u);
+                u = p(u);
             }
+            cycle.reverse();
+            return Err(cycle);
         }
-        Some(cost)
+        Ok((cost, prev))
     }
-    fn warshall_floyd_ap<'a, S, M>(
+    /// `prev[i][j]`: the previous vertex of `j` on the shortest path from `i`
+    fn warshall_floyd_ap_with_prev<'a, S, M>(
         &'g self,
         weight: &'a M,
-    ) -> <Self as VertexMap<'g, <Self as VertexMap<'g, S::T>>::Vmap>>::Vmap
+    ) -> AllPairsWithPrev<'g, Self, S::T>
     where
         Self: Vertices<'g>
             + VertexMap<'g, S::T>
-            + VertexMap<'g, <Self as VertexMap<'g, S::T>>::Vmap>
+            + VertexMap<'g, Vmap<'g, Self, S::T>>
+            + VertexMap<'g, Prev<'g, Self>>
+            + VertexMap<'g, PrevMap<'g, Self>>
             + AdjacencyView<'g, 'a, M, S::T>,
-        <Self as VertexMap<'g, S::T>>::Vmap: Clone,
         S: ShortestPathSemiRing,
     {
-        let mut cost = self.construct_vmap(|| self.construct_vmap(S::inf));
+        let mut cost: AllPairs<'g, Self, S::T> =
+            self.construct_vmap(|| self.construct_vmap(S::inf));
+        let mut prev: AllPairs<'g, Self, Prev<'g, Self>> =
+            self.construct_vmap(|| self.construct_vmap(|| None));
         for u in self.vertices() {
             *self.vmap_get_mut(self.vmap_get_mut(&mut cost, u), u) = S::source();
         }
         for u in self.vertices() {
             for a in self.aviews(weight, u) {
-                *self.vmap_get_mut(self.vmap_get_mut(&mut cost, u), a.vindex()) = a.avalue();
+                let v = a.vindex();
+                if S::add_assign(
+                    self.vmap_get_mut(self.vmap_get_mut(&mut cost, u), v),
+                    &a.avalue(),
+                ) {
+                    *self.vmap_get_mut(self.vmap_get_mut(&mut prev, u), v) = Some(u);
+                }
             }
         }
         for k in self.vertices() {
@@ -278,11 +426,423 @@ pub trait ShortestPathExt<'g>: GraphBase<'g> {
                     let d1 = self.vmap_get(self.vmap_get(&cost, i), k);
                     let d2 = self.vmap_get(self.vmap_get(&cost, k), j);
                     let nd = S::mul(d1, d2);
-                    S::add_assign(self.vmap_get_mut(self.vmap_get_mut(&mut cost, i), j), &nd);
+                    if S::add_assign(self.vmap_get_mut(self.vmap_get_mut(&mut cost, i), j), &nd) {
+                        let prev_k: &PrevMap<'g, Self> = self.vmap_get(&prev, k);
+                        let p: Prev<'g, Self> = *self.vmap_get(prev_k, j);
+                        *self.vmap_get_mut(self.vmap_get_mut(&mut prev, i), j) = p;
+                    }
                 }
             }
         }
-        cost
+        (cost, prev)
+    }
+    /// all pairs shortest path with negative edges, return `None` if a negative cycle exists
+    fn johnson_ap<'a, G, M>(&'g self, weight: &'a M) -> Option<AllPairs<'g, Self, Option<G::T>>>
+    where
+        Self: Vertices<'g>
+            + VertexMap<'g, Option<G::T>>
+            + VertexMap<'g, Vmap<'g, Self, Option<G::T>>>
+            + VertexMap<'g, Prev<'g, Self>>
+            + VertexMap<'g, PrevMap<'g, Self>>
+            + AdjacencyView<'g, 'a, M, Option<G::T>>
+            + VertexSize<'g>,
+        G: Group,
+        G::T: Ord,
+    {
+        self.johnson_ap_with_prev::<G, M>(weight)
+            .map(|(cost, _)| cost)
+    }
+    /// all pairs shortest path with negative edges, return `None` if a negative cycle exists
+    ///
+    /// `prev[i][j]`: the previous vertex of `j` on the shortest path from `i`
+    fn johnson_ap_with_prev<'a, G, M>(
+        &'g self,
+        weight: &'a M,
+    ) -> Option<AllPairsWithPrev<'g, Self, Option<G::T>>>
+    where
+        Self: Vertices<'g>
+            + VertexMap<'g, Option<G::T>>
+            + VertexMap<'g, Vmap<'g, Self, Option<G::T>>>
+            + VertexMap<'g, Prev<'g, Self>>
+            + VertexMap<'g, PrevMap<'g, Self>>
+            + AdjacencyView<'g, 'a, M, Option<G::T>>
+            + VertexSize<'g>,
+        G: Group,
+        G::T: Ord,
+    {
+        let h = self.bellman_ford_ms::<OptionSp<G>, M, _>(self.vertices(), weight, true)?;
+        let h = |u| {
+            <Self as VertexMap<'g, Option<G::T>>>::vmap_get(self, &h, u)
+                .as_ref()
+                .unwrap()
+        };
+        let mut cost: AllPairs<'g, Self, Option<G::T>> = self.construct_vmap(|| {
+            <Self as VertexMap<'g, Option<G::T>>>::construct_vmap(self, || None)
+        });
+        let mut prev: AllPairs<'g, Self, Prev<'g, Self>> = self.construct_vmap(|| {
+            <Self as VertexMap<'g, Prev<'g, Self>>>::construct_vmap(self, || None)
+        });
+        for s in self.vertices() {
+            let cost = self.vmap_get_mut(&mut cost, s);
+            let prev = self.vmap_get_mut(&mut prev, s);
+            let mut heap = BinaryHeap::new();
+            *self.vmap_get_mut(cost, s) = Some(G::unit());
+            heap.push(PartialIgnoredOrd(Reverse(G::unit()), s));
+            while let Some(PartialIgnoredOrd(Reverse(d), u)) = heap.pop() {
+                if <Self as VertexMap<'g, Option<G::T>>>::vmap_get(self, cost, u).as_ref()
+                    != Some(&d)
+                {
+                    continue;
+                }
+                for a in self.aviews(weight, u) {
+                    let v = a.vindex();
+                    if let Some(w) = a.avalue() {
+                        // reduced weight: h(u) + w - h(v) >= 0
+                        let w = G::rinv_operate(&G::operate(h(u), &w), h(v));
+                        let nd = G::operate(&d, &w);
+                        if OptionSp::<G>::add_assign(self.vmap_get_mut(cost, v), &Some(nd.clone()))
+                        {
+                            *self.vmap_get_mut(prev, v) = Some(u);
+                            heap.push(PartialIgnoredOrd(Reverse(nd), v));
+                        }
+                    }
+                }
+            }
+            for t in self.vertices() {
+                if let Some(d) = self.vmap_get_mut(cost, t) {
+                    *d = G::operate(&G::operate(&G::inverse(h(s)), d), h(t));
+                }
+            }
+        }
+        Some((cost, prev))
+    }
+    /// Restore the path to `target` from the previous vertex map.
+    fn restore_path(&'g self, prev: &PrevMap<'g, Self>, target: Self::VIndex) -> Vec<Self::VIndex>
+    where
+        Self: VertexMap<'g, Prev<'g, Self>>,
+    {
+        let mut path = vec![target];
+        let mut u = target;
+        while let Some(p) = *<Self as VertexMap<'g, Prev<'g, Self>>>::vmap_get(self, prev, u) {
+            path.push(p);
+            u = p;
+        }
+        path.reverse();
+        path
     }
 }
 impl<'g, G> ShortestPathExt<'g> for G where G: GraphBase<'g> {}
+
+/// 0-1 BFS, `f(u, v)` is called on each update of `v` from `u`
+fn bfs_distance_impl<'g, 'a, G, S, M, I, F>(
+    graph: &'g G,
+    sources: I,
+    weight: &'a M,
+    mut f: F,
+) -> Vmap<'g, G, S::T>
+where
+    G: ?Sized + VertexMap<'g, S::T> + AdjacencyView<'g, 'a, M, S::T>,
+    S: ShortestPathSemiRing,
+    I: IntoIterator<Item = G::VIndex>,
+    F: FnMut(G::VIndex, G::VIndex),
+{
+    let mut cost = graph.construct_vmap(S::inf);
+    let mut deq = VecDeque::new();
+    for source in sources.into_iter() {
+        *graph.vmap_get_mut(&mut cost, source) = S::source();
+        deq.push_back(source);
+    }
+    let zero = S::source();
+    while let Some(u) = deq.pop_front() {
+        for a in graph.aviews(weight, u) {
+            let v = a.vindex();
+            let w = a.avalue();
+            let nd = S::mul(graph.vmap_get(&cost, u), &w);
+            if S::add_assign(graph.vmap_get_mut(&mut cost, v), &nd) {
+                f(u, v);
+                if w == zero {
+                    deq.push_front(v);
+                } else {
+                    deq.push_back(v);
+                }
+            }
+        }
+    }
+    cost
+}
+
+/// `f(u, v)` is called on each update of `v` from `u`
+fn dijkstra_impl<'g, 'a, G, S, M, I, F>(
+    graph: &'g G,
+    sources: I,
+    weight: &'a M,
+    mut f: F,
+) -> Vmap<'g, G, S::T>
+where
+    G: ?Sized + VertexMap<'g, S::T> + AdjacencyView<'g, 'a, M, S::T>,
+    S: ShortestPathSemiRing,
+    I: IntoIterator<Item = G::VIndex>,
+    F: FnMut(G::VIndex, G::VIndex),
+{
+    let mut cost = graph.construct_vmap(S::inf);
+    let mut heap = BinaryHeap::new();
+    for source in sources.into_iter() {
+        *graph.vmap_get_mut(&mut cost, source) = S::source();
+        heap.push(PartialIgnoredOrd(Reverse(S::source()), source));
+    }
+    while let Some(PartialIgnoredOrd(Reverse(d), u)) = heap.pop() {
+        if graph.vmap_get(&cost, u) != &d {
+            continue;
+        }
+        let d = graph.vmap_get(&cost, u).clone();
+        for a in graph.aviews(weight, u) {
+            let v = a.vindex();
+            let nd = S::mul(&d, &a.avalue());
+            if S::add_assign(graph.vmap_get_mut(&mut cost, v), &nd) {
+                f(u, v);
+                heap.push(PartialIgnoredOrd(Reverse(nd), v));
+            }
+        }
+    }
+    cost
+}
+
+/// `f(u, v)` is called on each update of `v` from `u`
+///
+/// Relaxes `vsize - 1` rounds, and one more round if `check`.
+/// Also returns a vertex updated in the last round if every round updated some vertex,
+/// which means a negative cycle exists when `check`.
+fn bellman_ford_impl<'g, 'a, G, S, M, I, F>(
+    graph: &'g G,
+    sources: I,
+    weight: &'a M,
+    check: bool,
+    mut f: F,
+) -> (Vmap<'g, G, S::T>, Option<G::VIndex>)
+where
+    G: ?Sized
+        + Vertices<'g>
+        + VertexMap<'g, S::T>
+        + AdjacencyView<'g, 'a, M, S::T>
+        + VertexSize<'g>,
+    S: ShortestPathSemiRing,
+    I: IntoIterator<Item = G::VIndex>,
+    F: FnMut(G::VIndex, G::VIndex),
+{
+    let mut cost = graph.construct_vmap(S::inf);
+    for source in sources.into_iter() {
+        *graph.vmap_get_mut(&mut cost, source) = S::source();
+    }
+    let vsize = graph.vsize();
+    let rounds = if check {
+        vsize
+    } else {
+        vsize.saturating_sub(1)
+    };
+    let mut last = None;
+    for _ in 0..rounds {
+        last = None;
+        for u in graph.vertices() {
+            for a in graph.aviews(weight, u) {
+                let v = a.vindex();
+                let nd = S::mul(graph.vmap_get(&cost, u), &a.avalue());
+                if S::add_assign(graph.vmap_get_mut(&mut cost, v), &nd) {
+                    f(u, v);
+                    last = Some(v);
+                }
+            }
+        }
+        if last.is_none() {
+            break;
+        }
+    }
+    (cost, last)
+}
+
+/// queue based Bellman-Ford, `f(u, v)` is called on each update of `v` from `u`
+fn spfa_impl<'g, 'a, G, S, M, I, F>(
+    graph: &'g G,
+    sources: I,
+    weight: &'a M,
+    mut f: F,
+) -> Option<Vmap<'g, G, S::T>>
+where
+    G: ?Sized
+        + VertexMap<'g, S::T>
+        + VertexMap<'g, usize>
+        + VertexMap<'g, bool>
+        + AdjacencyView<'g, 'a, M, S::T>
+        + VertexSize<'g>,
+    S: ShortestPathSemiRing,
+    I: IntoIterator<Item = G::VIndex>,
+    F: FnMut(G::VIndex, G::VIndex),
+{
+    let mut cost = graph.construct_vmap(S::inf);
+    // number of edges of the current shortest path
+    let mut len = <G as VertexMap<'g, usize>>::construct_vmap(graph, || 0);
+    let mut in_queue = <G as VertexMap<'g, bool>>::construct_vmap(graph, || false);
+    let mut deq = VecDeque::new();
+    for source in sources.into_iter() {
+        *graph.vmap_get_mut(&mut cost, source) = S::source();
+        *graph.vmap_get_mut(&mut in_queue, source) = true;
+        deq.push_back(source);
+    }
+    let vsize = graph.vsize();
+    while let Some(u) = deq.pop_front() {
+        *graph.vmap_get_mut(&mut in_queue, u) = false;
+        for a in graph.aviews(weight, u) {
+            let v = a.vindex();
+            let nd = S::mul(graph.vmap_get(&cost, u), &a.avalue());
+            if S::add_assign(graph.vmap_get_mut(&mut cost, v), &nd) {
+                f(u, v);
+                // a shortest path has less than vsize edges without negative cycles
+                let l = *<G as VertexMap<'g, usize>>::vmap_get(graph, &len, u) + 1;
+                if l >= vsize {
+                    return None;
+                }
+                *graph.vmap_get_mut(&mut len, v) = l;
+                if !*<G as VertexMap<'g, bool>>::vmap_get(graph, &in_queue, v) {
+                    *graph.vmap_get_mut(&mut in_queue, v) = true;
+                    deq.push_back(v);
+                }
+            }
+        }
+    }
+    Some(cost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algebra::AdditiveOperation, graph::DirectedSparseGraph, rand, tools::Xorshift};
+
+    type Sp = OptionSp<AdditiveOperation<i64>>;
+
+    fn min_weight(edges: &[(usize, usize)], w: &[i64], u: usize, v: usize) -> Option<i64> {
+        edges
+            .iter()
+            .zip(w)
+            .filter(|(&e, _)| e == (u, v))
+            .map(|(_, &w)| w)
+            .min()
+    }
+
+    fn path_cost(edges: &[(usize, usize)], w: &[i64], path: &[usize]) -> i64 {
+        path.windows(2)
+            .map(|p| min_weight(edges, w, p[0], p[1]).unwrap())
+            .sum()
+    }
+
+    #[test]
+    fn test_shortest_path_with_prev() {
+        const Q: usize = 300;
+        const N: usize = 8;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), m: (0..=N * 3), lower: (-10..=0));
+            rand!(rng, edges: [(0..n, 0..n); m], w: [lower..=10; m]);
+            let graph = DirectedSparseGraph::from_edges(n, edges.clone());
+            let weight = |eid: usize| Some(w[eid]);
+            let (fw, fw_prev) = graph.warshall_floyd_ap_with_prev::<Sp, _>(&weight);
+            let has_negative_cycle = (0..n).any(|u| fw[u][u] < Some(0));
+            assert_eq!(
+                graph
+                    .johnson_ap::<AdditiveOperation<i64>, _>(&weight)
+                    .is_none(),
+                has_negative_cycle
+            );
+            for s in 0..n {
+                let spfa = graph.spfa_ss_with_prev::<Sp, _>(s, &weight);
+                assert_eq!(
+                    graph.spfa_ss::<Sp, _>(s, &weight),
+                    spfa.as_ref().map(|(cost, _)| cost.clone())
+                );
+                match graph.bellman_ford_ss_with_prev::<Sp, _>(s, &weight) {
+                    Ok((cost, prev)) => {
+                        let (spfa_cost, spfa_prev) = spfa.unwrap();
+                        assert_eq!(spfa_cost, cost);
+                        for (t, &d) in spfa_cost.iter().enumerate() {
+                            if let Some(d) = d {
+                                let path = graph.restore_path(&spfa_prev, t);
+                                assert_eq!(path[0], s);
+                                assert_eq!(path_cost(&edges, &w, &path), d);
+                            }
+                        }
+                        for (t, &d) in cost.iter().enumerate() {
+                            if let Some(d) = d {
+                                let path = graph.restore_path(&prev, t);
+                                assert_eq!(path[0], s);
+                                assert_eq!(path_cost(&edges, &w, &path), d);
+                            }
+                        }
+                    }
+                    Err(cycle) => {
+                        assert!(has_negative_cycle);
+                        assert!(spfa.is_none());
+                        let mut closed = cycle.clone();
+                        closed.push(cycle[0]);
+                        assert!(path_cost(&edges, &w, &closed) < 0);
+                    }
+                }
+            }
+            if has_negative_cycle {
+                continue;
+            }
+            let (johnson, johnson_prev) = graph
+                .johnson_ap_with_prev::<AdditiveOperation<i64>, _>(&weight)
+                .unwrap();
+            assert_eq!(johnson, fw);
+            for (s, fw) in fw.iter().enumerate() {
+                for (t, &d) in fw.iter().enumerate() {
+                    if let Some(d) = d {
+                        for prev in [&fw_prev[s], &johnson_prev[s]].iter() {
+                            let path = graph.restore_path(prev, t);
+                            assert_eq!(path[0], s);
+                            assert_eq!(path_cost(&edges, &w, &path), d);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_single_source_with_prev() {
+        const Q: usize = 300;
+        const N: usize = 8;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), m: (0..=N * 3), upper: (0..=10), s: (0..n));
+            rand!(rng, edges: [(0..n, 0..n); m], w: [0..=upper; m]);
+            let graph = DirectedSparseGraph::from_edges(n, edges.clone());
+            let weight = |eid: usize| Some(w[eid]);
+            let fw = graph.warshall_floyd_ap::<Sp, _>(&weight);
+            let (cost, prev) = if upper <= 1 {
+                graph.bfs_distance_ss_with_prev::<Sp, _>(s, &weight)
+            } else {
+                graph.dijkstra_ss_with_prev::<Sp, _>(s, &weight)
+            };
+            for (t, &d) in cost.iter().enumerate() {
+                assert_eq!(d, fw[s][t]);
+                if let Some(d) = d {
+                    let path = graph.restore_path(&prev, t);
+                    assert_eq!(path[0], s);
+                    assert_eq!(path_cost(&edges, &w, &path), d);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_warshall_floyd_parallel_edges() {
+        let edges = vec![(0, 1), (0, 1), (1, 2), (0, 1), (1, 2)];
+        let w = [5i64, 2, 4, 7, 3];
+        let graph = DirectedSparseGraph::from_edges(3, edges);
+        let weight = |eid: usize| Some(w[eid]);
+        let fw = graph.warshall_floyd_ap::<Sp, _>(&weight);
+        assert_eq!(fw[0], vec![Some(0), Some(2), Some(5)]);
+        assert_eq!(fw[1], vec![None, Some(0), Some(3)]);
+        let (fw, _) = graph.warshall_floyd_ap_with_prev::<Sp, _>(&weight);
+        assert_eq!(fw[0], vec![Some(0), Some(2), Some(5)]);
+    }
+}
@@ -0,0 +1,313 @@
+use super::{ConvolveSteps, FormalPowerSeries, FormalPowerSeriesCoefficient};
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    ops::{Add, Index, IndexMut, Mul, Neg, Sub},
+};
+
+/// $F(x, y)$ truncated as $\deg_x F < n$ and $\deg_y F < m$
+///
+/// The coefficient of $x^i y^j$ is at `data[i * m + j]`.
+pub struct BivariateFormalPowerSeries<T, C> {
+    pub data: Vec<T>,
+    n: usize,
+    m: usize,
+    _marker: PhantomData<C>,
+}
+
+impl<T, C> Debug for BivariateFormalPowerSeries<T, C>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BivariateFormalPowerSeries")
+            .field("data", &self.data)
+            .field("n", &self.n)
+            .field("m", &self.m)
+            .finish()
+    }
+}
+impl<T, C> Clone for BivariateFormalPowerSeries<T, C>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            n: self.n,
+            m: self.m,
+            _marker: PhantomData,
+        }
+    }
+}
+impl<T, C> PartialEq for BivariateFormalPowerSeries<T, C>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.n == other.n && self.m == other.m && self.data == other.data
+    }
+}
+impl<T, C> Eq for BivariateFormalPowerSeries<T, C> where T: Eq {}
+
+impl<T, C> Index<(usize, usize)> for BivariateFormalPowerSeries<T, C> {
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        assert!(j < self.m);
+        &self.data[i * self.m + j]
+    }
+}
+impl<T, C> IndexMut<(usize, usize)> for BivariateFormalPowerSeries<T, C> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        assert!(j < self.m);
+        &mut self.data[i * self.m + j]
+    }
+}
+
+impl<T, C> BivariateFormalPowerSeries<T, C> {
+    pub fn from_vec(n: usize, m: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), n * m);
+        Self {
+            data,
+            n,
+            m,
+            _marker: PhantomData,
+        }
+    }
+    pub fn from_fn<F>(n: usize, m: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let data = (0..n * m).map(|k| f(k / m, k % m)).collect();
+        Self::from_vec(n, m, data)
+    }
+    /// (bound of $\deg_x$, bound of $\deg_y$)
+    pub fn shape(&self) -> (usize, usize) {
+        (self.n, self.m)
+    }
+}
+
+impl<T, C> BivariateFormalPowerSeries<T, C>
+where
+    T: FormalPowerSeriesCoefficient,
+{
+    pub fn zeros(n: usize, m: usize) -> Self {
+        Self::from_fn(n, m, |_, _| T::zero())
+    }
+    /// coefficient of $x^i y^j$, zero if out of the shape
+    pub fn get(&self, i: usize, j: usize) -> T {
+        if i < self.n && j < self.m {
+            self[(i, j)].clone()
+        } else {
+            T::zero()
+        }
+    }
+    pub fn resized(&self, n: usize, m: usize) -> Self {
+        Self::from_fn(n, m, |i, j| self.get(i, j))
+    }
+    /// $[x^i]F$ as a series in $y$
+    pub fn coeff_x(&self, i: usize) -> FormalPowerSeries<T, C> {
+        FormalPowerSeries::from_vec((0..self.m).map(|j| self.get(i, j)).collect())
+    }
+    /// $F(-x, y)$
+    pub fn neg_x(&self) -> Self {
+        Self::from_fn(self.n, self.m, |i, j| {
+            if i & 1 == 0 {
+                self[(i, j)].clone()
+            } else {
+                -self[(i, j)].clone()
+            }
+        })
+    }
+    /// $\frac{\partial F}{\partial x}$
+    pub fn diff_x(&self) -> Self {
+        Self::from_fn(self.n.saturating_sub(1), self.m, |i, j| {
+            self[(i + 1, j)].clone() * T::from(i + 1)
+        })
+    }
+    /// $\int F dx$
+    pub fn integral_x(&self) -> Self {
+        Self::from_fn(self.n + 1, self.m, |i, j| {
+            if i == 0 {
+                T::zero()
+            } else {
+                self[(i - 1, j)].clone() / T::from(i)
+            }
+        })
+    }
+}
+
+impl<T, C> BivariateFormalPowerSeries<T, C>
+where
+    T: FormalPowerSeriesCoefficient,
+    C: ConvolveSteps<T = Vec<T>>,
+{
+    /// product truncated in the shape `(n, m)` by Kronecker substitution
+    pub fn mul_truncated(&self, rhs: &Self, n: usize, m: usize) -> Self {
+        let a = self.resized(self.n.min(n), self.m.min(m));
+        let b = rhs.resized(rhs.n.min(n), rhs.m.min(m));
+        if a.data.is_empty() || b.data.is_empty() {
+            return Self::zeros(n, m);
+        }
+        let s = a.m + b.m - 1;
+        let flatten = |f: &Self| {
+            let mut v = vec![T::zero(); (f.n - 1) * s + f.m];
+            for i in 0..f.n {
+                v[i * s..i * s + f.m].clone_from_slice(&f.data[i * f.m..(i + 1) * f.m]);
+            }
+            v
+        };
+        let c = C::convolve(flatten(&a), flatten(&b));
+        Self::from_fn(n, m, |i, j| {
+            if j < s {
+                c.get(i * s + j).cloned().unwrap_or_else(T::zero)
+            } else {
+                T::zero()
+            }
+        })
+    }
+    /// $F^{-1} \bmod x^{deg}$ where $F(0, y)$ is invertible
+    pub fn inv(&self, deg: usize) -> Self {
+        let m = self.m;
+        let mut g = Self::from_vec(1, m, self.coeff_x(0).inv(m).resized(m).data);
+        let mut k = 1;
+        while k < deg {
+            k = (k * 2).min(deg);
+            let mut h = -self.mul_truncated(&g, k, m);
+            h[(0, 0)] += T::one() + T::one();
+            g = g.mul_truncated(&h, k, m);
+        }
+        g.resized(deg, m)
+    }
+    /// $\log F \bmod x^{deg}$ where $F(0, y) = 1$
+    pub fn log(&self, deg: usize) -> Self {
+        if deg == 0 {
+            return Self::zeros(0, self.m);
+        }
+        self.diff_x()
+            .mul_truncated(&self.inv(deg - 1), deg - 1, self.m)
+            .integral_x()
+    }
+    /// $\exp F \bmod x^{deg}$ where $F(0, y) = 0$
+    pub fn exp(&self, deg: usize) -> Self {
+        let m = self.m;
+        let mut g = Self::from_fn(1, m, |_, j| if j == 0 { T::one() } else { T::zero() });
+        let mut k = 1;
+        while k < deg {
+            k = (k * 2).min(deg);
+            let mut h = self.resized(k, m) - g.log(k);
+            h[(0, 0)] += T::one();
+            g = g.mul_truncated(&h, k, m);
+        }
+        g.resized(deg, m)
+    }
+}
+
+impl<T, C> Add for BivariateFormalPowerSeries<T, C>
+where
+    T: FormalPowerSeriesCoefficient,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        let (n, m) = (self.n.max(rhs.n), self.m.max(rhs.m));
+        Self::from_fn(n, m, |i, j| self.get(i, j) + rhs.get(i, j))
+    }
+}
+impl<T, C> Sub for BivariateFormalPowerSeries<T, C>
+where
+    T: FormalPowerSeriesCoefficient,
+{
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (n, m) = (self.n.max(rhs.n), self.m.max(rhs.m));
+        Self::from_fn(n, m, |i, j| self.get(i, j) - rhs.get(i, j))
+    }
+}
+impl<T, C> Neg for BivariateFormalPowerSeries<T, C>
+where
+    T: FormalPowerSeriesCoefficient,
+{
+    type Output = Self;
+    fn neg(mut self) -> Self::Output {
+        for x in self.data.iter_mut() {
+            *x = -x.clone();
+        }
+        self
+    }
+}
+/// product truncated in the larger shape
+impl<T, C> Mul for BivariateFormalPowerSeries<T, C>
+where
+    T: FormalPowerSeriesCoefficient,
+    C: ConvolveSteps<T = Vec<T>>,
+{
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_truncated(&rhs, self.n.max(rhs.n), self.m.max(rhs.m))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        math::Convolve998244353,
+        num::{montgomery::MInt998244353, Zero},
+        rand,
+        tools::Xorshift,
+    };
+
+    type M = MInt998244353;
+    type B = BivariateFormalPowerSeries<M, Convolve998244353>;
+
+    fn random(rng: &mut Xorshift, n: usize, m: usize) -> B {
+        B::from_fn(n, m, |_, _| M::from(rng.rand64()))
+    }
+
+    #[test]
+    fn test_bivariate_mul() {
+        let mut rng = Xorshift::default();
+        for _ in 0..100 {
+            rand!(rng, n1: (0..10), m1: (0..10), n2: (0..10), m2: (0..10), n: (0..20), m: (0..20));
+            let (a, b) = (random(&mut rng, n1, m1), random(&mut rng, n2, m2));
+            let c = a.mul_truncated(&b, n, m);
+            assert_eq!(c.shape(), (n, m));
+            for i in 0..n {
+                for j in 0..m {
+                    let mut x = M::zero();
+                    for k in 0..=i {
+                        for l in 0..=j {
+                            x += a.get(k, l) * b.get(i - k, j - l);
+                        }
+                    }
+                    assert_eq!(c[(i, j)], x);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bivariate_inv_log_exp() {
+        let mut rng = Xorshift::default();
+        for _ in 0..50 {
+            rand!(rng, n: (1..40), m: (1..40), deg: (0..40));
+            let mut f = random(&mut rng, n, m);
+            f[(0, 0)] = M::from(rng.gen(1..100u32));
+            let g = f.inv(deg);
+            assert_eq!(g.shape(), (deg, m));
+            assert_eq!(
+                f.mul_truncated(&g, deg, m),
+                B::from_fn(deg, m, |i, j| M::from((i == 0 && j == 0) as u32))
+            );
+
+            for j in 0..m {
+                f[(0, j)] = M::from((j == 0) as u32);
+            }
+            let l = f.log(deg);
+            for j in 0..m {
+                assert_eq!(l.get(0, j), M::zero());
+            }
+            assert_eq!(l.exp(deg), f.resized(deg, m));
+        }
+    }
+}
@@ -1,3 +1,6 @@
+use super::Zero;
+use std::ops::{Add, Mul, Sub};
+
 pub trait ConvolveSteps {
     type T;
     type F;
@@ -13,3 +16,102 @@ pub trait ConvolveSteps {
         Self::inverse_transform(a, len)
     }
 }
+
+/// `convolve` of transform based implementations falls back to
+/// [`convolve_karatsuba`] when the shorter input is at most this length
+pub const CONVOLVE_FALLBACK_THRESHOLD: usize = 64;
+
+const NAIVE_THRESHOLD: usize = 32;
+
+/// [`ConvolveSteps::convolve`] by the steps of `C`, or by [`convolve_karatsuba`] for short inputs
+pub fn convolve_with_fallback<C, T>(a: Vec<T>, b: Vec<T>) -> Vec<T>
+where
+    C: ConvolveSteps<T = Vec<T>>,
+    T: Clone + Zero + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    if a.len().min(b.len()) <= CONVOLVE_FALLBACK_THRESHOLD {
+        return convolve_karatsuba(&a, &b);
+    }
+    let len = a.len() + b.len() - 1;
+    let mut a = C::transform(a, len);
+    let b = C::transform(b, len);
+    C::multiply(&mut a, &b);
+    C::inverse_transform(a, len)
+}
+
+pub fn convolve_naive<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    let mut c = vec![T::zero(); (a.len() + b.len()).saturating_sub(1)];
+    for (i, a) in a.iter().enumerate() {
+        for (c, b) in c[i..].iter_mut().zip(b) {
+            *c = c.clone() + a.clone() * b.clone();
+        }
+    }
+    c
+}
+
+/// Karatsuba convolution in $O(nm^{\log_2 3 - 1})$ for $n \geq m$
+pub fn convolve_karatsuba<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Clone + Zero + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.len() <= NAIVE_THRESHOLD {
+        return convolve_naive(a, b);
+    }
+    let mut c = vec![T::zero(); a.len() + b.len() - 1];
+    let add = |c: &mut [T], x: Vec<T>| {
+        for (c, x) in c.iter_mut().zip(x) {
+            *c = c.clone() + x;
+        }
+    };
+    if b.len() * 2 <= a.len() {
+        for (i, a) in a.chunks(b.len()).enumerate() {
+            add(&mut c[i * b.len()..], convolve_karatsuba(a, b));
+        }
+        return c;
+    }
+    let m = a.len() / 2;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+    let sum = |x: &[T], y: &[T]| -> Vec<T> {
+        let mut s = y.to_vec();
+        s.resize_with(x.len().max(y.len()), T::zero);
+        for (s, x) in s.iter_mut().zip(x) {
+            *s = s.clone() + x.clone();
+        }
+        s
+    };
+    let z0 = convolve_karatsuba(a0, b0);
+    let z2 = convolve_karatsuba(a1, b1);
+    let mut z1 = convolve_karatsuba(&sum(a0, a1), &sum(b0, b1));
+    for (z1, z) in z1.iter_mut().zip(&z0) {
+        *z1 = z1.clone() - z.clone();
+    }
+    for (z1, z) in z1.iter_mut().zip(&z2) {
+        *z1 = z1.clone() - z.clone();
+    }
+    add(&mut c, z0);
+    add(&mut c[m..], z1);
+    add(&mut c[m * 2..], z2);
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rand, tools::Xorshift};
+
+    #[test]
+    fn test_convolve_karatsuba() {
+        let mut rng = Xorshift::default();
+        for _ in 0..200 {
+            rand!(rng, n: (0..300), m: (0..300), a: [-100i64..100; n], b: [-100i64..100; m]);
+            let c = convolve_naive(&a, &b);
+            assert_eq!(c.len(), (n + m).saturating_sub(1));
+            assert_eq!(convolve_karatsuba(&a, &b), c);
+        }
+    }
+}
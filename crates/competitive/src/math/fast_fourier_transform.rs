@@ -1,7 +1,13 @@
-use super::{AssociatedValue, Complex, ConvolveSteps, One, Zero};
+use super::{
+    convolve_with_fallback, AssociatedValue, Complex, ConvolveSteps, MInt, MIntConvert, One, Zero,
+};
+use std::marker::PhantomData;
 
 pub enum ConvolveRealFft {}
 
+/// arbitrary mod convolution by FFT with values split into 3 parts of 11 bits
+pub struct MIntConvolveFft<M>(PhantomData<fn() -> M>);
+
 enum RotateCache {}
 impl RotateCache {
     fn ensure(n: usize) {
@@ -125,6 +131,88 @@ impl ConvolveSteps for ConvolveRealFft {
             *f *= *g;
         }
     }
+    fn convolve(a: Self::T, b: Self::T) -> Self::T {
+        convolve_with_fallback::<Self, _>(a, b)
+    }
+}
+
+const SPLIT_BITS: u32 = 11;
+const SPLIT_MASK: u32 = (1 << SPLIT_BITS) - 1;
+
+impl<M> ConvolveSteps for MIntConvolveFft<M>
+where
+    M: MIntConvert + MIntConvert<u32> + MIntConvert<u64>,
+{
+    type T = Vec<MInt<M>>;
+    /// spectra of the 3 parts, or spectra of (c0 + i c1, c2 + i c3, c4) after multiplication
+    type F = [Vec<Complex<f64>>; 3];
+    fn length(t: &Self::T) -> usize {
+        t.len()
+    }
+    fn transform(t: Self::T, len: usize) -> Self::F {
+        let n = len.max(2).next_power_of_two();
+        let mut f01 = vec![Complex::zero(); n];
+        let mut f2 = vec![Complex::zero(); n];
+        for ((f01, f2), t) in f01.iter_mut().zip(&mut f2).zip(t) {
+            let x = <M as MIntConvert<u32>>::into(t.inner());
+            *f01 = Complex::new(
+                (x & SPLIT_MASK) as f64,
+                (x >> SPLIT_BITS & SPLIT_MASK) as f64,
+            );
+            f2.re = (x >> (SPLIT_BITS * 2)) as f64;
+        }
+        fft(&mut f01);
+        bit_reverse(&mut f01);
+        fft(&mut f2);
+        bit_reverse(&mut f2);
+        // separate spectra of real sequences packed as x0 + i x1
+        let mut f0 = vec![Complex::zero(); n];
+        let mut f1 = vec![Complex::zero(); n];
+        for k in 0..n {
+            let (x, y) = (f01[k], f01[(n - k) & (n - 1)].conjugate());
+            f0[k] = (x + y) * 0.5;
+            f1[k] = (x - y).transpose().conjugate() * 0.5;
+        }
+        [f0, f1, f2]
+    }
+    fn inverse_transform(f: Self::F, len: usize) -> Self::T {
+        let n = len.max(2).next_power_of_two();
+        let inv = 1. / n as f64;
+        let [mut f01, mut f23, mut f4] = f;
+        for f in [&mut f01, &mut f23, &mut f4].iter_mut() {
+            assert_eq!(f.len(), n);
+            bit_reverse(f);
+            ifft(f);
+        }
+        let round = |x: f64| MInt::<M>::from((x * inv).round() as u64);
+        let base = MInt::<M>::from(1u32 << SPLIT_BITS);
+        (0..len)
+            .map(|i| {
+                let c = [
+                    round(f01[i].re),
+                    round(f01[i].im),
+                    round(f23[i].re),
+                    round(f23[i].im),
+                    round(f4[i].re),
+                ];
+                c.iter().rev().fold(MInt::zero(), |acc, &c| acc * base + c)
+            })
+            .collect()
+    }
+    fn multiply(f: &mut Self::F, g: &Self::F) {
+        assert_eq!(f[0].len(), g[0].len());
+        for k in 0..f[0].len() {
+            let (a0, a1, a2) = (f[0][k], f[1][k], f[2][k]);
+            let (b0, b1, b2) = (g[0][k], g[1][k], g[2][k]);
+            let i = Complex::i();
+            f[0][k] = a0 * b0 + (a0 * b1 + a1 * b0) * i;
+            f[1][k] = a0 * b2 + a1 * b1 + a2 * b0 + (a1 * b2 + a2 * b1) * i;
+            f[2][k] = a2 * b2;
+        }
+    }
+    fn convolve(a: Self::T, b: Self::T) -> Self::T {
+        convolve_with_fallback::<Self, _>(a, b)
+    }
 }
 
 pub fn fft(a: &mut [Complex<f64>]) {
@@ -195,3 +283,30 @@ fn test_convolve_fft() {
         }
     }
 }
+
+#[test]
+fn test_convolve_split_fft() {
+    use crate::math::MIntConvolve;
+    use crate::{
+        num::mint_basic::{MInt1000000007, Modulo1000000007},
+        tools::Xorshift,
+    };
+    let mut rng = Xorshift::default();
+    for n in (0..50usize).chain([1 << 12, 1 << 16]) {
+        let n = n.max(rng.gen(0..=n));
+        let m = rng.gen(0..=n.max(100));
+        let a: Vec<_> = (0..n).map(|_| MInt1000000007::from(rng.rand64())).collect();
+        let b: Vec<_> = (0..m).map(|_| MInt1000000007::from(rng.rand64())).collect();
+        let c = MIntConvolve::<Modulo1000000007>::convolve(a.clone(), b.clone());
+        let d = MIntConvolveFft::<Modulo1000000007>::convolve(a.clone(), b.clone());
+        assert_eq!(c, d);
+        let len = (n + m).saturating_sub(1);
+        let mut fa = MIntConvolveFft::<Modulo1000000007>::transform(a, len);
+        let fb = MIntConvolveFft::<Modulo1000000007>::transform(b, len);
+        MIntConvolveFft::<Modulo1000000007>::multiply(&mut fa, &fb);
+        assert_eq!(
+            MIntConvolveFft::<Modulo1000000007>::inverse_transform(fa, len),
+            c
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use super::{One, PrimeList, Zero};
+use std::ops::{Add, Mul, Sub};
+
+fn isqrt(n: u64) -> u64 {
+    let mut s = (n as f64).sqrt() as u64;
+    while s * s > n {
+        s -= 1;
+    }
+    while (s + 1) * (s + 1) <= n {
+        s += 1;
+    }
+    s
+}
+
+/// set of $\left\lfloor\frac{n}{i}\right\rfloor$ in ascending order
+///
+/// Tables over the set are indexed in the same order.
+#[derive(Debug, Clone)]
+pub struct FloorQuotients {
+    n: u64,
+    sqrt: u64,
+    values: Vec<u64>,
+}
+
+impl FloorQuotients {
+    pub fn new(n: u64) -> Self {
+        assert!(n >= 1, "n must be positive");
+        let sqrt = isqrt(n);
+        let mut values: Vec<u64> = (1..=sqrt).collect();
+        values.extend((1..=sqrt).rev().map(|i| n / i).filter(|&x| x > sqrt));
+        Self { n, sqrt, values }
+    }
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+    pub fn values(&self) -> &[u64] {
+        &self.values
+    }
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+    /// index of `x`, which must be in the set
+    pub fn index(&self, x: u64) -> usize {
+        if x <= self.sqrt {
+            x as usize - 1
+        } else {
+            self.values.len() - (self.n / x) as usize
+        }
+    }
+    /// sums of `g(p)` over primes `p <= x` for all `x` by Lucy's algorithm in $O(n^{3/4})$,
+    /// where `g` is completely multiplicative and `prefix(x)` is $\sum_{i=1}^x g(i)$
+    pub fn lucy_prime_sum<T, G, P>(&self, g: G, prefix: P) -> Vec<T>
+    where
+        T: Clone + Sub<Output = T> + Mul<Output = T>,
+        G: Fn(u64) -> T,
+        P: Fn(u64) -> T,
+    {
+        let one = prefix(1);
+        let mut s: Vec<T> = self
+            .values
+            .iter()
+            .map(|&x| prefix(x) - one.clone())
+            .collect();
+        for &p in PrimeList::new(self.sqrt).primes() {
+            let gp = g(p);
+            let base = s[self.index(p - 1)].clone();
+            let p2 = p * p;
+            for i in (0..self.values.len()).rev() {
+                let x = self.values[i];
+                if x < p2 {
+                    break;
+                }
+                let y = s[self.index(x / p)].clone() - base.clone();
+                s[i] = s[i].clone() - gp.clone() * y;
+            }
+        }
+        s
+    }
+    /// number of primes `p <= x` for all `x`
+    pub fn prime_counts(&self) -> Vec<u64> {
+        self.lucy_prime_sum(|_| 1u64, |x| x)
+    }
+    /// $\sum_{i=1}^n f(i)$ by Min_25 sieve, where `f` is multiplicative, `prime_sum` is the sums
+    /// of `f(p)` over primes `p <= x` for all `x`, and `f(p, e)` is $f(p^e)$
+    pub fn min25_sieve<T, F>(&self, prime_sum: &[T], f: F) -> T
+    where
+        T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+        F: Fn(u64, u32) -> T,
+    {
+        assert_eq!(prime_sum.len(), self.values.len());
+        let primes = PrimeList::new(self.sqrt);
+        T::one() + self.min25_rec(primes.primes(), prime_sum, &f, self.n, 0)
+    }
+    /// sum of `f(i)` for `2 <= i <= x` whose least prime factor is at least `primes[j]`
+    fn min25_rec<T, F>(&self, primes: &[u64], prime_sum: &[T], f: &F, x: u64, j: usize) -> T
+    where
+        T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+        F: Fn(u64, u32) -> T,
+    {
+        let mut res = prime_sum[self.index(x)].clone();
+        if j > 0 {
+            res = res - prime_sum[self.index(primes[j - 1])].clone();
+        }
+        for (k, &p) in primes.iter().enumerate().skip(j) {
+            if p * p > x {
+                break;
+            }
+            let (mut pe, mut e) = (p, 1);
+            while pe * p <= x {
+                res = res
+                    + f(p, e) * self.min25_rec(primes, prime_sum, f, x / pe, k + 1)
+                    + f(p, e + 1);
+                pe *= p;
+                e += 1;
+            }
+        }
+        res
+    }
+    /// $\sum_{i=1}^x (f * g)(i)$ by Dirichlet hyperbola method in $O(\sqrt{x})$, where `f` and
+    /// `g` are prefix sums for all quotients and `x` is in the set
+    pub fn dirichlet_mul_at<T>(&self, f: &[T], g: &[T], x: u64) -> T
+    where
+        T: Clone + Zero + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        let s = isqrt(x);
+        let mut res = T::zero();
+        let (mut pf, mut pg) = (T::zero(), T::zero());
+        for d in 1..=s {
+            let i = self.index(d);
+            let j = self.index(x / d);
+            res = res + (f[i].clone() - pf) * g[j].clone() + (g[i].clone() - pg) * f[j].clone();
+            pf = f[i].clone();
+            pg = g[i].clone();
+        }
+        res - pf * pg
+    }
+    /// prefix sums of the Dirichlet product `f * g` for all quotients in $O(n^{3/4})$
+    pub fn dirichlet_mul<T>(&self, f: &[T], g: &[T]) -> Vec<T>
+    where
+        T: Clone + Zero + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        self.values
+            .iter()
+            .map(|&x| self.dirichlet_mul_at(f, g, x))
+            .collect()
+    }
+}
+
+/// number of primes `p <= n` in $O(n^{3/4})$
+pub fn prime_count(n: u64) -> u64 {
+    if n < 2 {
+        return 0;
+    }
+    *FloorQuotients::new(n).prime_counts().last().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{math::prime_factors, num::mint_basic::MInt998244353, tools::Xorshift};
+
+    #[test]
+    fn test_floor_quotients() {
+        for n in 1..=1000u64 {
+            let q = FloorQuotients::new(n);
+            let mut expected: Vec<_> = (1..=n).map(|i| n / i).collect();
+            expected.sort_unstable();
+            expected.dedup();
+            assert_eq!(q.values(), &expected[..]);
+            for (i, &x) in q.values().iter().enumerate() {
+                assert_eq!(q.index(x), i);
+            }
+        }
+    }
+
+    #[test]
+    fn test_floor_quotients_large() {
+        for &s in [316_228u64, 1_000_000].iter() {
+            for n in s * s - 1..=s * s + 1 {
+                let q = FloorQuotients::new(n);
+                let sqrt = if n < s * s { s - 1 } else { s };
+                assert_eq!(q.len() as u64, sqrt * 2 - (n / sqrt == sqrt) as u64);
+                assert_eq!(q.values()[sqrt as usize - 1], sqrt);
+                assert!(q.values().windows(2).all(|w| w[0] < w[1]));
+                for (i, &x) in q.values().iter().enumerate() {
+                    assert_eq!(n / (n / x), x);
+                    assert_eq!(q.index(x), i);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_prime_sum() {
+        const N: u64 = 3000;
+        let mut is_prime = vec![true; N as usize + 1];
+        is_prime[0] = false;
+        is_prime[1] = false;
+        for i in 2..=N as usize {
+            for j in (i * i..=N as usize).step_by(i) {
+                is_prime[j] = false;
+            }
+        }
+        let mut rng = Xorshift::default();
+        for n in (1..=60).chain((0..50).map(|_| rng.gen(1..=N))) {
+            let q = FloorQuotients::new(n);
+            let count = q.prime_counts();
+            let sum = q.lucy_prime_sum(|p| p, |x| x * (x + 1) / 2);
+            let sum_mod = q.lucy_prime_sum(MInt998244353::from, |x| {
+                MInt998244353::from(x * (x + 1) / 2)
+            });
+            for (i, &x) in q.values().iter().enumerate() {
+                let primes: Vec<_> = (2..=x).filter(|&p| is_prime[p as usize]).collect();
+                assert_eq!(count[i], primes.len() as u64);
+                assert_eq!(sum[i], primes.iter().sum::<u64>());
+                assert_eq!(sum_mod[i], MInt998244353::from(primes.iter().sum::<u64>()));
+            }
+            assert_eq!(prime_count(n), count[q.len() - 1]);
+        }
+        assert_eq!(prime_count(0), 0);
+        assert_eq!(prime_count(1_000_000), 78498);
+    }
+
+    #[test]
+    fn test_min25_sieve() {
+        const N: u64 = 3000;
+        let mut rng = Xorshift::default();
+        for n in (1..=60).chain((0..50).map(|_| rng.gen(1..=N))) {
+            let q = FloorQuotients::new(n);
+            let count = q.prime_counts();
+            let sum = q.lucy_prime_sum(|p| p, |x| x * (x + 1) / 2);
+
+            // Euler's totient
+            let prime_sum: Vec<_> = sum.iter().zip(&count).map(|(s, c)| s - c).collect();
+            let phi = q.min25_sieve(&prime_sum, |p, e| (p - 1) * p.pow(e - 1));
+            let expected: u64 = (1..=n)
+                .map(|i| {
+                    prime_factors(i)
+                        .into_iter()
+                        .map(|(p, e)| (p - 1) * p.pow(e - 1))
+                        .product::<u64>()
+                })
+                .sum();
+            assert_eq!(phi, expected);
+
+            // Möbius
+            let prime_sum: Vec<_> = count.iter().map(|&c| -(c as i64)).collect();
+            let mertens = q.min25_sieve(&prime_sum, |_, e| if e == 1 { -1i64 } else { 0 });
+            let expected: i64 = (1..=n)
+                .map(|i| {
+                    let f = prime_factors(i);
+                    if f.iter().any(|&(_, e)| e > 1) {
+                        0
+                    } else {
+                        [1, -1][f.len() & 1]
+                    }
+                })
+                .sum();
+            assert_eq!(mertens, expected);
+
+            // number of divisors
+            let prime_sum: Vec<_> = count.iter().map(|&c| c * 2).collect();
+            let d = q.min25_sieve(&prime_sum, |_, e| e as u64 + 1);
+            let expected: u64 = (1..=n)
+                .map(|i| (1..=i).filter(|&j| i % j == 0).count() as u64)
+                .sum();
+            assert_eq!(d, expected);
+
+            // hyperbola: 1 * 1 = d, 1 * id = sigma
+            let ones: Vec<_> = q.values().to_vec();
+            let ids: Vec<_> = q.values().iter().map(|&x| x * (x + 1) / 2).collect();
+            let dd = q.dirichlet_mul(&ones, &ones);
+            let sigma = q.dirichlet_mul(&ones, &ids);
+            for (i, &x) in q.values().iter().enumerate() {
+                let ed: u64 = (1..=x).map(|k| x / k).sum();
+                let es: u64 = (1..=x).map(|k| k * (x / k)).sum();
+                assert_eq!(dd[i], ed);
+                assert_eq!(sigma[i], es);
+            }
+            assert_eq!(q.dirichlet_mul_at(&ones, &ones, n), d);
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use super::*;
+
+type B<T, C> = BivariateFormalPowerSeries<T, C>;
+
+impl<T, C> FormalPowerSeries<T, C>
+where
+    T: FormalPowerSeriesCoefficient,
+    C: ConvolveSteps<T = Vec<T>>,
+{
+    /// $[x^n] f^k$ for $0 \leq k < m$ by power projection in $O((n + m) \log^2 (n + m))$
+    ///
+    /// Bostan–Mori on $\frac{1}{1 - y f(x)}$ with $\deg_y$ doubled while $n$ is halved.
+    pub fn power_projection(&self, mut n: usize, m: usize) -> Vec<T> {
+        if m == 0 {
+            return vec![];
+        }
+        let mut p = B::<T, C>::from_fn(1, 1, |_, _| T::one());
+        let mut q = B::<T, C>::from_fn(n + 1, 2.min(m), |i, j| match (i, j) {
+            (0, 0) => T::one(),
+            (_, 0) => T::zero(),
+            _ => -self.data.get(i).cloned().unwrap_or_else(T::zero),
+        });
+        while n > 0 {
+            let qm = q.neg_x();
+            let ((pn, pk), (qn, qk)) = (p.shape(), q.shape());
+            let a = p.mul_truncated(&qm, pn + qn - 1, (pk + qk - 1).min(m));
+            let b = q.mul_truncated(&qm, qn * 2 - 1, (qk * 2 - 1).min(m));
+            let r = n & 1;
+            n /= 2;
+            p = B::from_fn(n + 1, a.shape().1, |i, j| a.get(i * 2 + r, j));
+            q = B::from_fn(n + 1, b.shape().1, |i, j| b.get(i * 2, j));
+        }
+        let f = p.coeff_x(0).resized(m) * q.coeff_x(0).resized(m).inv(m);
+        f.resized(m).data
+    }
+    /// $f(g(x)) \bmod x^{deg}$ in $O((n + deg) \log^2 (n + deg))$
+    ///
+    /// Transposition of [`power_projection`](Self::power_projection).
+    pub fn compose(&self, g: &Self, deg: usize) -> Self {
+        let m = self.length();
+        if deg == 0 || m == 0 {
+            return Self::zeros(deg);
+        }
+        let mut n = deg - 1;
+        let mut q = B::<T, C>::from_fn(n + 1, 2.min(m), |i, j| match (i, j) {
+            (0, 0) => T::one(),
+            (_, 0) => T::zero(),
+            _ => -g.data.get(i).cloned().unwrap_or_else(T::zero),
+        });
+        let mut qs = vec![];
+        while n > 0 {
+            let qm = q.neg_x();
+            let b = q.mul_truncated(&qm, n + 1, (q.shape().1 * 2 - 1).min(m));
+            qs.push((n, qm));
+            n /= 2;
+            q = B::from_fn(n + 1, b.shape().1, |i, j| b.get(i * 2, j));
+        }
+        // transposed steps from the last
+        let r = q.coeff_x(0).resized(m).inv(m);
+        let fr = Self::from_vec(self.data.iter().rev().cloned().collect()) * r;
+        let mut p = B::<T, C>::from_fn(1, (1 << qs.len().min(31)).min(m), |_, j| {
+            fr.data[m - 1 - j].clone()
+        });
+        for (t, (n, qm)) in qs.into_iter().enumerate().rev() {
+            let (rows, cols) = (n + 1, p.shape().1);
+            let a = B::from_fn(rows, cols, |i, j| {
+                let (i, j) = (n - i, cols - 1 - j);
+                if i & 1 == n & 1 {
+                    p.get(i / 2, j)
+                } else {
+                    T::zero()
+                }
+            });
+            let c = a.mul_truncated(&qm, rows, cols);
+            p = B::from_fn(rows, (1 << t.min(31)).min(m), |i, j| {
+                c.get(n - i, cols - 1 - j)
+            });
+        }
+        Self::from_vec((0..deg).map(|i| p.get(deg - 1 - i, 0)).collect())
+    }
+    /// $g$ such that $f(g(x)) \equiv x \bmod x^{deg}$ where $f(0) = 0$ and $f'(0) \neq 0$
+    ///
+    /// By Lagrange inversion, $[x^{n-i}] \left(\frac{x}{g(x)}\right)^n = \frac{n}{i} [x^n] f^i$.
+    pub fn compositional_inverse(&self, deg: usize) -> Self {
+        if deg <= 1 {
+            return Self::zeros(deg);
+        }
+        debug_assert!(self[0].is_zero() && !self[1].is_zero());
+        let f1 = self[1].clone();
+        if deg == 2 {
+            return Self::from_vec(vec![T::zero(), T::one() / f1]);
+        }
+        let n = deg - 1;
+        let pp = self.power_projection(n, n + 1);
+        let mut h = Self::zeros(n);
+        for (i, x) in pp.into_iter().enumerate().skip(1) {
+            h[n - i] = x * T::from(n) / T::from(i);
+        }
+        let h0 = h[0].clone();
+        let xg = ((h / h0).log(n) / T::from(n)).exp(n) * f1;
+        let mut g = xg.inv(n);
+        g.data.insert(0, T::zero());
+        g
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{num::montgomery::MInt998244353 as M, rand, tools::Xorshift};
+
+    type F = Fps998244353;
+
+    #[test]
+    fn test_compose() {
+        let mut rng = Xorshift::default();
+        for _ in 0..100 {
+            rand!(rng, n: (0..60), m: (0..60), deg: (0..60));
+            let f = F::from_vec((0..n).map(|_| M::from(rng.rand64())).collect());
+            let g = F::from_vec((0..m).map(|_| M::from(rng.rand64())).collect());
+            let mut h = F::zeros(deg);
+            for x in f.data.iter().rev() {
+                h = (h * g.clone()).prefix(deg).resized(deg);
+                h[0] += x;
+            }
+            assert_eq!(f.compose(&g, deg).data, h.prefix(deg).data);
+        }
+    }
+
+    #[test]
+    fn test_compositional_inverse() {
+        let mut rng = Xorshift::default();
+        for _ in 0..100 {
+            rand!(rng, n: (2..60), deg: (0..60));
+            let mut f = F::from_vec((0..n).map(|_| M::from(rng.rand64())).collect());
+            f[0] = M::zero();
+            f[1] = M::from(rng.gen(1..100u32));
+            let g = f.compositional_inverse(deg);
+            assert_eq!(g.length(), deg);
+            let x = F::from_vec((0..deg).map(|i| M::from((i == 1) as u32)).collect());
+            assert_eq!(f.compose(&g, deg).data, x.data);
+            assert_eq!(g.compose(&f, deg).data, x.data);
+        }
+    }
+
+    #[test]
+    fn test_power_projection() {
+        let mut rng = Xorshift::default();
+        for _ in 0..50 {
+            rand!(rng, n: (0..60), m: (0..60), l: (0..60));
+            let f = F::from_vec((0..l).map(|_| M::from(rng.rand64())).collect());
+            let res = f.power_projection(n, m);
+            let mut g = F::from_vec(vec![M::one()]);
+            for r in res {
+                assert_eq!(r, g.data.get(n).cloned().unwrap_or_else(M::zero));
+                g = (g * f.clone()).prefix(n + 1);
+            }
+        }
+    }
+}
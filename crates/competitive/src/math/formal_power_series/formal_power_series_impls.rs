@@ -375,47 +375,6 @@ where
         }
         p[0].clone() / q[0].clone()
     }
-    fn middle_product(self, other: &C::F, deg: usize) -> Self {
-        let n = self.length();
-        let mut s = C::transform(self.reversed().data, deg);
-        C::multiply(&mut s, other);
-        Self::from_vec((C::inverse_transform(s, deg))[n - 1..].to_vec())
-    }
-    pub fn multipoint_evaluation(self, points: &[T]) -> Vec<T> {
-        let n = points.len();
-        if n <= 32 {
-            return points.iter().map(|p| self.eval(p.clone())).collect();
-        }
-        let mut subproduct_tree = Vec::with_capacity(n * 2);
-        subproduct_tree.resize_with(n, Zero::zero);
-        for x in points {
-            subproduct_tree.push(Self::from_vec(vec![-x.clone(), T::one()]));
-        }
-        for i in (1..n).rev() {
-            subproduct_tree[i] = &subproduct_tree[i * 2] * &subproduct_tree[i * 2 + 1];
-        }
-        let mut uptree_t = Vec::with_capacity(n * 2);
-        uptree_t.resize_with(1, Zero::zero);
-        subproduct_tree.reverse();
-        subproduct_tree.pop();
-        let m = self.length();
-        let v = subproduct_tree.pop().unwrap().reversed().resized(m);
-        let s = C::transform(self.data, m * 2);
-        uptree_t.push(v.inv(m).middle_product(&s, m * 2).resized(n).reversed());
-        for i in 1..n {
-            let subl = subproduct_tree.pop().unwrap();
-            let subr = subproduct_tree.pop().unwrap();
-            let (dl, dr) = (subl.length(), subr.length());
-            let len = dl.max(dr) + uptree_t[i].length();
-            let s = C::transform(uptree_t[i].data.to_vec(), len);
-            uptree_t.push(subr.middle_product(&s, len).prefix(dl));
-            uptree_t.push(subl.middle_product(&s, len).prefix(dr));
-        }
-        uptree_t[n..]
-            .iter()
-            .map(|u| u.data.get(0).cloned().unwrap_or_else(Zero::zero))
-            .collect()
-    }
     pub fn product_all<I>(iter: I, deg: usize) -> Self
     where
         I: IntoIterator<Item = Self>,
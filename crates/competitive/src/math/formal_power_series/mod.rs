@@ -1,6 +1,8 @@
+pub use self::subproduct_tree::SubproductTree;
 use super::{
-    berlekamp_massey, montgomery::MInt998244353, Convolve998244353, ConvolveSteps, MInt,
-    MIntConvert, MIntConvolve, MemorizedFactorial, One, PartialIgnoredOrd, Zero,
+    berlekamp_massey, montgomery::MInt998244353, BivariateFormalPowerSeries, Convolve998244353,
+    ConvolveSteps, MInt, MIntConvert, MIntConvolve, MemorizedFactorial, One, PartialIgnoredOrd,
+    Zero,
 };
 use std::{
     marker::PhantomData,
@@ -58,5 +60,8 @@ where
     }
 }
 
+mod composition;
 mod formal_power_series_impls;
 mod formal_power_series_nums;
+mod polynomial_gcd;
+mod subproduct_tree;
@@ -0,0 +1,270 @@
+use super::*;
+
+/// $M$ with $M (a, b)^\top = (a', b')^\top$
+type Mat<T, C> = [[FormalPowerSeries<T, C>; 2]; 2];
+
+impl<T, C> FormalPowerSeries<T, C>
+where
+    T: FormalPowerSeriesCoefficient,
+    C: ConvolveSteps<T = Vec<T>>,
+{
+    fn trimmed(mut self) -> Self {
+        self.trim_tail_zeros();
+        self
+    }
+    fn mat_identity() -> Mat<T, C> {
+        [[Self::one(), Self::zero()], [Self::zero(), Self::one()]]
+    }
+    fn mat_mul(a: &Mat<T, C>, b: &Mat<T, C>) -> Mat<T, C> {
+        let e = |i: usize, j: usize| (&a[i][0] * &b[0][j] + &a[i][1] * &b[1][j]).trimmed();
+        [[e(0, 0), e(0, 1)], [e(1, 0), e(1, 1)]]
+    }
+    fn mat_apply(m: &Mat<T, C>, p: &mut (Self, Self)) {
+        let a = (&m[0][0] * &p.0 + &m[0][1] * &p.1).trimmed();
+        let b = (&m[1][0] * &p.0 + &m[1][1] * &p.1).trimmed();
+        *p = (a, b);
+    }
+    /// one step of Euclid $(a, b) \to (b, a \bmod b)$
+    fn euclid_step(m: &mut Mat<T, C>, p: &mut (Self, Self)) {
+        let (q, r) = p.0.clone().div_rem(p.1.clone());
+        let [m0, m1] = [m[0].clone(), m[1].clone()];
+        let row = [
+            (&m0[0] - &q * &m1[0]).trimmed(),
+            (&m0[1] - &q * &m1[1]).trimmed(),
+        ];
+        *m = [m1, row];
+        *p = (std::mem::replace(&mut p.1, Self::zero()), r);
+    }
+    /// reduces `(a, b)` with $\deg a > \deg b$ until $\deg b < \lfloor \frac{\deg a + 1}{2} \rfloor$
+    fn half_gcd(a: &Self, b: &Self) -> Mat<T, C> {
+        let k = a.length() / 2;
+        if b.length() <= k {
+            return Self::mat_identity();
+        }
+        let m = Self::half_gcd(&(a >> k), &(b >> k));
+        let mut p = (a.clone(), b.clone());
+        Self::mat_apply(&m, &mut p);
+        if p.1.length() <= k {
+            return m;
+        }
+        let mut m = m;
+        Self::euclid_step(&mut m, &mut p);
+        if p.1.length() <= k {
+            return m;
+        }
+        let j = (2 * k).saturating_sub(p.0.length() - 1);
+        let s = Self::half_gcd(&(&p.0 >> j), &(&p.1 >> j));
+        Self::mat_mul(&s, &m)
+    }
+    /// $M$ with $M (a, b)^\top = (\gcd(a, b), 0)^\top$ up to a constant factor
+    fn gcd_matrix(a: &Self, b: &Self) -> Mat<T, C> {
+        let mut p = (a.clone().trimmed(), b.clone().trimmed());
+        let mut res = Self::mat_identity();
+        if p.0.length() <= p.1.length() {
+            if p.1.length() == 0 {
+                return res;
+            }
+            Self::euclid_step(&mut res, &mut p);
+        }
+        while p.1.length() > 0 {
+            let mut m = Self::half_gcd(&p.0, &p.1);
+            Self::mat_apply(&m, &mut p);
+            if p.1.length() > 0 {
+                Self::euclid_step(&mut m, &mut p);
+            }
+            res = Self::mat_mul(&m, &res);
+        }
+        res
+    }
+    /// monic $\gcd(f, g)$ in $O(n \log^2 n)$
+    pub fn gcd(&self, rhs: &Self) -> Self {
+        self.extended_gcd(rhs).0
+    }
+    /// $(d, x, y)$ such that $fx + gy = d$ where $d$ is the monic $\gcd(f, g)$ in $O(n \log^2 n)$
+    ///
+    /// $\deg x < \deg g - \deg d$ and $\deg y < \deg f - \deg d$ unless either is constant.
+    pub fn extended_gcd(&self, rhs: &Self) -> (Self, Self, Self) {
+        let [[x, y], _] = Self::gcd_matrix(self, rhs);
+        let d = (&x * self + &y * rhs).trimmed();
+        match d.data.last().cloned() {
+            Some(lc) => (d / lc.clone(), x / lc.clone(), y / lc),
+            None => (d, x, y),
+        }
+    }
+    /// $f^{-1} \bmod g$ if $\gcd(f, g) = 1$
+    pub fn inv_mod(&self, modulus: &Self) -> Option<Self> {
+        let (d, x, _) = self.extended_gcd(modulus);
+        if d.length() != 1 {
+            return None;
+        }
+        let modulus = modulus.clone().trimmed();
+        if x.length() < modulus.length() {
+            Some(x)
+        } else {
+            Some(x % modulus)
+        }
+    }
+    /// resultant $\mathrm{Res}(f, g)$ by the Euclidean algorithm in $O(nm)$
+    pub fn resultant(&self, rhs: &Self) -> T {
+        let mut a = self.clone().trimmed().data;
+        let mut b = rhs.clone().trimmed().data;
+        if a.is_empty() || b.is_empty() {
+            return T::zero();
+        }
+        let pow = |x: &T, e: usize| (0..e).fold(T::one(), |acc, _| acc * x);
+        let mut res = T::one();
+        loop {
+            let (n, m) = (a.len() - 1, b.len() - 1);
+            if n == 0 {
+                return res * pow(&a[0], m);
+            }
+            if m == 0 {
+                return res * pow(&b[0], n);
+            }
+            if (n * m) & 1 == 1 {
+                res = -res;
+            }
+            if n < m {
+                std::mem::swap(&mut a, &mut b);
+                continue;
+            }
+            // Res(b, a) = lc(b)^{n - deg r} Res(b, r)
+            let lc_inv = T::one() / b[m].clone();
+            for i in (m..=n).rev() {
+                let c = a[i].clone() * &lc_inv;
+                for j in 0..=m {
+                    a[i - m + j] -= c.clone() * &b[j];
+                }
+            }
+            a.truncate(m);
+            while let Some(x) = a.last() {
+                if !x.is_zero() {
+                    break;
+                }
+                a.pop();
+            }
+            if a.is_empty() {
+                return T::zero();
+            }
+            res *= pow(&b[m], n + 1 - a.len());
+            std::mem::swap(&mut a, &mut b);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{num::montgomery::MInt998244353 as M, rand, tools::Xorshift};
+
+    type F = Fps998244353;
+
+    fn random_poly(rng: &mut Xorshift, n: usize, q: u32) -> F {
+        F::from_vec((0..n).map(|_| M::from(rng.gen(0..q))).collect())
+    }
+
+    fn naive_gcd(a: &F, b: &F) -> F {
+        let (mut a, mut b) = (a.clone(), b.clone());
+        a.trim_tail_zeros();
+        b.trim_tail_zeros();
+        while b.length() > 0 {
+            let r = a % b.clone();
+            a = b;
+            b = r;
+        }
+        match a.data.last().cloned() {
+            Some(lc) => a / lc,
+            None => a,
+        }
+    }
+
+    fn determinant(mut a: Vec<Vec<M>>) -> M {
+        let n = a.len();
+        let mut d = M::one();
+        for i in 0..n {
+            let p = match (i..n).find(|&p| !a[p][i].is_zero()) {
+                Some(p) => p,
+                None => return M::zero(),
+            };
+            if p != i {
+                a.swap(i, p);
+                d = -d;
+            }
+            d *= a[i][i];
+            let inv = a[i][i].inv();
+            for j in i + 1..n {
+                let c = a[j][i] * inv;
+                let row = a[i].clone();
+                for (x, y) in a[j][i..].iter_mut().zip(&row[i..]) {
+                    *x -= c * *y;
+                }
+            }
+        }
+        d
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        let mut rng = Xorshift::default();
+        for _ in 0..300 {
+            rand!(rng, n: (0..80), m: (0..80), l: (0..40), q: (0..3));
+            let q = [2, 3, 998244353][q];
+            let c = random_poly(&mut rng, l, q);
+            let a = random_poly(&mut rng, n, q) * c.clone();
+            let b = random_poly(&mut rng, m, q) * c;
+            let (d, x, y) = a.extended_gcd(&b);
+            assert_eq!(d.data, naive_gcd(&a, &b).data);
+            let mut e = &x * &a + &y * &b;
+            e.trim_tail_zeros();
+            assert_eq!(e.data, d.data);
+            assert_eq!(a.gcd(&b).data, d.data);
+            let len = |f: &F| f.clone().trimmed().length();
+            // degree of the zero polynomial is -1
+            let deg = |f: &F| len(f) as i64 - 1;
+            if deg(&a) > 0 && deg(&b) > 0 {
+                assert!(deg(&x) < deg(&b) - deg(&d));
+                assert!(deg(&y) < deg(&a) - deg(&d));
+            }
+            if let Some(h) = a.inv_mod(&b) {
+                assert!(h.length() < len(&b).max(1));
+                let mut e = (&h * &a) % b.clone().trimmed();
+                e.trim_tail_zeros();
+                if deg(&b) > 0 {
+                    assert_eq!(e.data, vec![M::one()]);
+                }
+            } else {
+                assert_ne!(d.length(), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_resultant() {
+        let mut rng = Xorshift::default();
+        for _ in 0..300 {
+            rand!(rng, n: (0..15), m: (0..15), q: (0..3));
+            let q = [2, 3, 998244353][q];
+            let a = random_poly(&mut rng, n, q).trimmed();
+            let b = random_poly(&mut rng, m, q).trimmed();
+            let expected = if a.length() == 0 || b.length() == 0 {
+                M::zero()
+            } else {
+                // Sylvester matrix
+                let (n, m) = (a.length() - 1, b.length() - 1);
+                let mut s = vec![vec![M::zero(); n + m]; n + m];
+                for i in 0..m {
+                    for (j, x) in a.data.iter().rev().enumerate() {
+                        s[i][i + j] = *x;
+                    }
+                }
+                for i in 0..n {
+                    for (j, x) in b.data.iter().rev().enumerate() {
+                        s[m + i][i + j] = *x;
+                    }
+                }
+                determinant(s)
+            };
+            assert_eq!(a.resultant(&b), expected);
+        }
+    }
+}
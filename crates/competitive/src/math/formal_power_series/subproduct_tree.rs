@@ -0,0 +1,282 @@
+use super::*;
+
+/// product tree of $(x - x_i)$ for evaluation and interpolation at fixed points
+///
+/// Node `i` has children `2i` and `2i+1`, and the leaf of `points[i]` is `n+i`.
+#[derive(Debug)]
+pub struct SubproductTree<T, C> {
+    points: Vec<T>,
+    tree: Vec<FormalPowerSeries<T, C>>,
+}
+
+impl<T, C> SubproductTree<T, C>
+where
+    T: FormalPowerSeriesCoefficient,
+    C: ConvolveSteps<T = Vec<T>>,
+{
+    pub fn new(points: Vec<T>) -> Self {
+        let n = points.len();
+        if n == 0 {
+            let tree = vec![FormalPowerSeries::zero(), FormalPowerSeries::one()];
+            return Self { points, tree };
+        }
+        let mut tree = Vec::with_capacity(n * 2);
+        tree.resize_with(n, Zero::zero);
+        for x in points.iter() {
+            tree.push(FormalPowerSeries::from_vec(vec![-x.clone(), T::one()]));
+        }
+        for i in (1..n).rev() {
+            tree[i] = &tree[i * 2] * &tree[i * 2 + 1];
+        }
+        Self { points, tree }
+    }
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+    /// $\prod_i (x - x_i)$
+    pub fn product(&self) -> &FormalPowerSeries<T, C> {
+        &self.tree[1]
+    }
+    /// $f(x_i)$ for all points in $O(n \log^2 n + m \log m)$
+    pub fn multipoint_evaluation(&self, f: &FormalPowerSeries<T, C>) -> Vec<T> {
+        let n = self.len();
+        let m = f.length();
+        if n <= 32 || m == 0 {
+            return self.points.iter().map(|p| f.eval(p.clone())).collect();
+        }
+        let mut uptree_t = Vec::with_capacity(n * 2);
+        uptree_t.resize_with(1, Zero::zero);
+        let v = self.tree[1].clone().reversed().resized(m);
+        let s = C::transform(f.data.clone(), m * 2);
+        uptree_t.push(v.inv(m).middle_product(&s, m * 2).resized(n).reversed());
+        for i in 1..n {
+            let (subl, subr) = (&self.tree[i * 2], &self.tree[i * 2 + 1]);
+            let (dl, dr) = (subl.length(), subr.length());
+            let len = dl.max(dr) + uptree_t[i].length();
+            let s = C::transform(uptree_t[i].data.to_vec(), len);
+            uptree_t.push(subr.middle_product(&s, len).prefix(dl));
+            uptree_t.push(subl.middle_product(&s, len).prefix(dr));
+        }
+        uptree_t[n..]
+            .iter()
+            .map(|u| u.data.first().cloned().unwrap_or_else(Zero::zero))
+            .collect()
+    }
+    /// polynomial of degree less than $n$ through $(x_i, y_i)$ in $O(n \log^2 n)$
+    ///
+    /// Points must be distinct.
+    pub fn interpolation(&self, values: &[T]) -> FormalPowerSeries<T, C> {
+        let n = self.len();
+        assert_eq!(n, values.len());
+        if n == 0 {
+            return FormalPowerSeries::zero();
+        }
+        let d = self.multipoint_evaluation(&self.tree[1].clone().diff());
+        let mut f = Vec::with_capacity(n * 2);
+        f.resize_with(n, Zero::zero);
+        for (y, d) in values.iter().zip(d) {
+            f.push(FormalPowerSeries::from_vec(vec![y.clone() / d]));
+        }
+        for i in (1..n).rev() {
+            f[i] = &f[i * 2] * &self.tree[i * 2 + 1] + &f[i * 2 + 1] * &self.tree[i * 2];
+        }
+        f.swap_remove(1).resized(n)
+    }
+}
+
+impl<T, C> FormalPowerSeries<T, C>
+where
+    T: FormalPowerSeriesCoefficient,
+    C: ConvolveSteps<T = Vec<T>>,
+{
+    fn middle_product(&self, other: &C::F, deg: usize) -> Self {
+        let n = self.length();
+        let mut s = C::transform(self.data.iter().rev().cloned().collect(), deg);
+        C::multiply(&mut s, other);
+        Self::from_vec((C::inverse_transform(s, deg))[n - 1..].to_vec())
+    }
+    pub fn multipoint_evaluation(self, points: &[T]) -> Vec<T> {
+        SubproductTree::<T, C>::new(points.to_vec()).multipoint_evaluation(&self)
+    }
+    /// polynomial of degree less than $n$ through $(x_i, y_i)$ in $O(n \log^2 n)$
+    pub fn interpolation(points: &[T], values: &[T]) -> Self {
+        SubproductTree::<T, C>::new(points.to_vec()).interpolation(values)
+    }
+}
+
+impl<M, C> FormalPowerSeries<MInt<M>, C>
+where
+    M: MIntConvert<usize>,
+    C: ConvolveSteps<T = Vec<MInt<M>>>,
+{
+    /// $f(m), f(m+1), \dots, f(m+k-1)$ from $f(0), \dots, f(n-1)$ where $\deg f < n$
+    ///
+    /// `f` needs factorials up to $n$ and $n$ must not exceed the modulus.
+    pub fn shift_of_sampling_points_of_polynomial(
+        values: &[MInt<M>],
+        m: usize,
+        k: usize,
+        f: &MemorizedFactorial<M>,
+    ) -> Vec<MInt<M>> {
+        let n = values.len();
+        if n == 0 {
+            return vec![MInt::zero(); k];
+        }
+        let p = M::mod_into();
+        assert!(n <= p);
+        let mut res = Vec::with_capacity(k);
+        while res.len() < k {
+            // split the queries where $m+j$ wraps around the modulus
+            let s = (m + res.len()) % p;
+            let len = (k - res.len()).min(p - s);
+            if s < n {
+                // known samples overlap with the queries
+                let t = n.min(s + len);
+                res.extend_from_slice(&values[s..t]);
+                if t < s + len {
+                    res.extend(Self::shift_of_sampling_points_without_wrap(
+                        values,
+                        t,
+                        s + len - t,
+                        f,
+                    ));
+                }
+            } else {
+                res.extend(Self::shift_of_sampling_points_without_wrap(
+                    values, s, len, f,
+                ));
+            }
+        }
+        res
+    }
+    /// requires $n \le m$ and $m+k \le$ modulus
+    fn shift_of_sampling_points_without_wrap(
+        values: &[MInt<M>],
+        m: usize,
+        k: usize,
+        f: &MemorizedFactorial<M>,
+    ) -> Vec<MInt<M>> {
+        let n = values.len();
+        // f(m+j) = \prod_{t=0}^{n-1} (m+j-t) \sum_i a_i / (m+j-i)
+        let a = Self::from_vec(
+            values
+                .iter()
+                .enumerate()
+                .map(|(i, &y)| {
+                    let c = y * f.inv_fact[i] * f.inv_fact[n - 1 - i];
+                    if (n - 1 - i) & 1 == 1 {
+                        -c
+                    } else {
+                        c
+                    }
+                })
+                .collect(),
+        );
+        let len = n + k - 1;
+        let base = m + 1 - n;
+        let mut acc = Vec::with_capacity(len + 1);
+        acc.push(MInt::one());
+        for t in 0..len {
+            let x = acc[t] * MInt::from(base + t);
+            acc.push(x);
+        }
+        let mut inv = acc[len].inv();
+        let mut b = vec![MInt::zero(); len];
+        for t in (0..len).rev() {
+            b[t] = inv * acc[t];
+            inv *= MInt::from(base + t);
+        }
+        let c = (a * Self::from_vec(b.clone())).resized(len);
+        let mut prod = acc[n];
+        let mut res = Vec::with_capacity(k);
+        for j in 0..k {
+            res.push(c[n - 1 + j] * prod);
+            if j + 1 < k {
+                prod *= MInt::from(m + j + 1) * b[j];
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{num::montgomery::MInt998244353 as M, rand, tools::Xorshift};
+
+    type F = Fps998244353;
+
+    #[test]
+    fn test_subproduct_tree() {
+        let mut rng = Xorshift::default();
+        for _ in 0..100 {
+            rand!(rng, n: (0..120), m: (0..120));
+            let f = F::from_vec((0..m).map(|_| M::from(rng.rand64())).collect());
+            let mut points: Vec<_> = (0..n).map(|_| M::from(rng.rand64())).collect();
+            points.sort_unstable_by_key(|x| u32::from(*x));
+            points.dedup();
+            let n = points.len();
+            let tree = SubproductTree::<M, Convolve998244353>::new(points.clone());
+            let expected: Vec<_> = points.iter().map(|&x| f.eval(x)).collect();
+            assert_eq!(tree.multipoint_evaluation(&f), expected);
+            assert_eq!(f.clone().multipoint_evaluation(&points), expected);
+            for &x in &points {
+                assert_eq!(tree.product().eval(x), M::zero());
+            }
+            assert_eq!(tree.product().length(), n + 1);
+
+            let values: Vec<_> = (0..n).map(|_| M::from(rng.rand64())).collect();
+            let g = tree.interpolation(&values);
+            assert_eq!(g.length(), n);
+            assert_eq!(tree.multipoint_evaluation(&g), values);
+            let g = f.clone().resized(n);
+            let values: Vec<_> = points.iter().map(|&x| g.eval(x)).collect();
+            assert_eq!(F::interpolation(&points, &values).data, g.data);
+        }
+    }
+
+    #[test]
+    fn test_shift_of_sampling_points() {
+        let mut rng = Xorshift::default();
+        let fact = MemorizedFactorial::new(200);
+        for _ in 0..100 {
+            rand!(rng, n: (0..100), m: (0..300), k: (0..100));
+            let f = F::from_vec((0..n).map(|_| M::from(rng.rand64())).collect());
+            let values: Vec<_> = (0..n).map(|i| f.eval(M::from(i))).collect();
+            let expected: Vec<_> = (m..m + k).map(|i| f.eval(M::from(i))).collect();
+            assert_eq!(
+                F::shift_of_sampling_points_of_polynomial(&values, m, k, &fact),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_shift_of_sampling_points_wrap() {
+        let mut rng = Xorshift::default();
+        let fact = MemorizedFactorial::new(200);
+        let p = 998_244_353usize;
+        for _ in 0..100 {
+            rand!(rng, n: (0..100), m: (p - 150..p), k: (0..300));
+            let f = F::from_vec((0..n).map(|_| M::from(rng.rand64())).collect());
+            let values: Vec<_> = (0..n).map(|i| f.eval(M::from(i))).collect();
+            let expected: Vec<_> = (m..m + k).map(|i| f.eval(M::from(i % p))).collect();
+            assert_eq!(
+                F::shift_of_sampling_points_of_polynomial(&values, m, k, &fact),
+                expected
+            );
+        }
+        let values = [M::from(0u32), M::from(1u32)];
+        let expected = vec![M::from(p - 1), M::from(0u32), M::from(1u32)];
+        assert_eq!(
+            F::shift_of_sampling_points_of_polynomial(&values, p - 1, 3, &fact),
+            expected
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use crate::data_structure::BitSet;
+use std::ops::{Add, Index, IndexMut, Mul};
+
+/// matrix over GF(2) with [`BitSet`] rows
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Gf2Matrix {
+    pub shape: (usize, usize),
+    pub data: Vec<BitSet>,
+}
+
+impl Gf2Matrix {
+    pub fn zeros(shape: (usize, usize)) -> Self {
+        Self {
+            shape,
+            data: vec![BitSet::new(shape.1); shape.0],
+        }
+    }
+    pub fn eye(shape: (usize, usize)) -> Self {
+        let mut res = Self::zeros(shape);
+        for i in 0..shape.0.min(shape.1) {
+            res.data[i].set(i, true);
+        }
+        res
+    }
+    pub fn from_vec(data: Vec<BitSet>) -> Self {
+        Self {
+            shape: (
+                data.len(),
+                data.first().map(BitSet::size).unwrap_or_default(),
+            ),
+            data,
+        }
+    }
+    pub fn get(&self, i: usize, j: usize) -> bool {
+        self.data[i].get(j)
+    }
+    pub fn set(&mut self, i: usize, j: usize, b: bool) {
+        self.data[i].set(j, b)
+    }
+    pub fn transpose(&self) -> Self {
+        let mut res = Self::zeros((self.shape.1, self.shape.0));
+        for (i, row) in self.data.iter().enumerate() {
+            for j in 0..self.shape.1 {
+                if row.get(j) {
+                    res.data[j].set(i, true);
+                }
+            }
+        }
+        res
+    }
+    /// reduced row echelon form, `f(i, j, add)` is called on each row swap or `row[i] ^= row[j]`
+    fn row_reduction_with<F>(&mut self, mut f: F) -> Vec<usize>
+    where
+        F: FnMut(usize, usize, bool),
+    {
+        let (n, m) = self.shape;
+        let mut pivots = vec![];
+        for c in 0..m {
+            let r = pivots.len();
+            if r == n {
+                break;
+            }
+            let pivot = match (r..n).find(|&p| self.data[p].get(c)) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            if pivot != r {
+                self.data.swap(r, pivot);
+                f(r, pivot, false);
+            }
+            let row = self.data[r].clone();
+            for i in (0..n).filter(|&i| i != r) {
+                if self.data[i].get(c) {
+                    self.data[i] ^= &row;
+                    f(i, r, true);
+                }
+            }
+            pivots.push(c);
+        }
+        pivots
+    }
+    /// reduced row echelon form, return pivot columns
+    pub fn row_reduction(&mut self) -> Vec<usize> {
+        self.row_reduction_with(|_, _, _| {})
+    }
+    pub fn rank(&mut self) -> usize {
+        self.row_reduction().len()
+    }
+    pub fn determinant(&mut self) -> bool {
+        assert_eq!(self.shape.0, self.shape.1);
+        self.rank() == self.shape.0
+    }
+    /// a solution of $Ax = b$ and a basis of the kernel of $A$
+    pub fn solve_system_of_linear_equations_with_kernel(
+        &self,
+        b: &BitSet,
+    ) -> Option<(BitSet, Vec<BitSet>)> {
+        let (n, m) = self.shape;
+        assert_eq!(n, b.size());
+        let mut a = self.clone();
+        let mut b = b.clone();
+        let pivots = a.row_reduction_with(|i, j, add| {
+            if add {
+                let x = b.get(i) ^ b.get(j);
+                b.set(i, x);
+            } else {
+                let (x, y) = (b.get(i), b.get(j));
+                b.set(i, y);
+                b.set(j, x);
+            }
+        });
+        if (pivots.len()..n).any(|i| b.get(i)) {
+            return None;
+        }
+        let mut x = BitSet::new(m);
+        let mut is_pivot = vec![false; m];
+        for (i, &j) in pivots.iter().enumerate() {
+            x.set(j, b.get(i));
+            is_pivot[j] = true;
+        }
+        let mut basis = vec![];
+        for f in (0..m).filter(|&f| !is_pivot[f]) {
+            let mut v = BitSet::new(m);
+            v.set(f, true);
+            for (i, &j) in pivots.iter().enumerate() {
+                v.set(j, a.data[i].get(f));
+            }
+            basis.push(v);
+        }
+        Some((x, basis))
+    }
+    pub fn inverse(&self) -> Option<Self> {
+        assert_eq!(self.shape.0, self.shape.1);
+        let n = self.shape.0;
+        let mut a = self.clone();
+        let mut inv = Self::eye((n, n));
+        let rank = a
+            .row_reduction_with(|i, j, add| {
+                if add {
+                    let row = inv.data[j].clone();
+                    inv.data[i] ^= &row;
+                } else {
+                    inv.data.swap(i, j);
+                }
+            })
+            .len();
+        if rank == n {
+            Some(inv)
+        } else {
+            None
+        }
+    }
+}
+
+impl Index<usize> for Gf2Matrix {
+    type Output = BitSet;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+impl IndexMut<usize> for Gf2Matrix {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+impl Add for &Gf2Matrix {
+    type Output = Gf2Matrix;
+    fn add(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.shape, rhs.shape);
+        let mut res = self.clone();
+        for (x, y) in res.data.iter_mut().zip(&rhs.data) {
+            *x ^= y;
+        }
+        res
+    }
+}
+impl Mul for &Gf2Matrix {
+    type Output = Gf2Matrix;
+    /// Method of Four Russians in $O(\frac{nlm}{w \log n})$
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.shape.1, rhs.shape.0);
+        const K: usize = 8;
+        let (n, l, m) = (self.shape.0, self.shape.1, rhs.shape.1);
+        let mut res = Gf2Matrix::zeros((n, m));
+        let mut table = vec![BitSet::new(m); 1 << K];
+        for t in (0..l).step_by(K) {
+            let k = K.min(l - t);
+            for s in 1usize..1 << k {
+                let low = s.trailing_zeros() as usize;
+                table[s] = &table[s & (s - 1)] ^ &rhs.data[t + low];
+            }
+            for (c, a) in res.data.iter_mut().zip(&self.data) {
+                let s = a.get_bits(t, k) as usize;
+                if s != 0 {
+                    *c ^= &table[s];
+                }
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rand, tools::Xorshift};
+
+    fn random_matrix(rng: &mut Xorshift, shape: (usize, usize), p: f64) -> Gf2Matrix {
+        let mut a = Gf2Matrix::zeros(shape);
+        for i in 0..shape.0 {
+            for j in 0..shape.1 {
+                a.set(i, j, rng.gen_bool(p));
+            }
+        }
+        a
+    }
+
+    fn apply(a: &Gf2Matrix, x: &BitSet) -> BitSet {
+        let mut y = BitSet::new(a.shape.0);
+        for i in 0..a.shape.0 {
+            y.set(i, (a[i].clone() & x).count_ones() & 1 == 1);
+        }
+        y
+    }
+
+    #[test]
+    fn test_mul() {
+        let mut rng = Xorshift::default();
+        for _ in 0..100 {
+            rand!(rng, n: (0..80), l: (0..80), m: (0..80));
+            let a = random_matrix(&mut rng, (n, l), 0.5);
+            let b = random_matrix(&mut rng, (l, m), 0.5);
+            let c = &a * &b;
+            assert_eq!(c.shape, (n, m));
+            for i in 0..n {
+                for j in 0..m {
+                    let x = (0..l).filter(|&k| a.get(i, k) && b.get(k, j)).count();
+                    assert_eq!(c.get(i, j), x & 1 == 1);
+                }
+            }
+            assert_eq!(c.transpose(), &b.transpose() * &a.transpose());
+        }
+    }
+
+    #[test]
+    fn test_row_reduction() {
+        let mut rng = Xorshift::default();
+        for _ in 0..200 {
+            rand!(rng, n: (1..80), m: (1..80));
+            let m = if rng.gen_bool(0.3) { n } else { m };
+            let p = [0.05, 0.5, 0.95][rng.gen(0..3)];
+            let a = random_matrix(&mut rng, (n, m), p);
+            let rank = a.clone().rank();
+            assert_eq!(rank, a.transpose().rank());
+            let b = if rng.gen_bool(0.5) {
+                apply(&a, &random_matrix(&mut rng, (1, m), 0.5)[0])
+            } else {
+                random_matrix(&mut rng, (1, n), 0.5)[0].clone()
+            };
+            let basis = a.solve_system_of_linear_equations_with_kernel(&BitSet::new(n));
+            let basis = basis.unwrap().1;
+            assert_eq!(basis.len(), m - rank);
+            for v in basis.iter() {
+                assert_eq!(apply(&a, v), BitSet::new(n));
+            }
+            assert_eq!(Gf2Matrix::from_vec(basis).rank(), m - rank);
+            if let Some((x, _)) = a.solve_system_of_linear_equations_with_kernel(&b) {
+                assert_eq!(apply(&a, &x), b);
+            } else {
+                let mut c = Gf2Matrix::zeros((n, m + 1));
+                for i in 0..n {
+                    for j in 0..m {
+                        c.set(i, j, a.get(i, j));
+                    }
+                    c.set(i, m, b.get(i));
+                }
+                assert_eq!(c.rank(), rank + 1);
+            }
+
+            if n == m {
+                let inv = a.inverse();
+                assert_eq!(a.clone().determinant(), inv.is_some());
+                if let Some(inv) = inv {
+                    assert_eq!(&a * &inv, Gf2Matrix::eye((n, n)));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,313 @@
+use super::{One, Zero};
+use crate::algebra::{AbelianGroup, AbelianMonoid, Invertible, Ring, SemiRing};
+use std::ops::Mul;
+
+/// linear sieve with smallest prime factors
+#[derive(Clone, Debug)]
+pub struct LinearSieve {
+    primes: Vec<usize>,
+    /// smallest prime factor
+    spf: Vec<usize>,
+    /// exponent of the smallest prime factor
+    exp: Vec<u32>,
+    /// power of the smallest prime factor
+    pow: Vec<usize>,
+}
+
+impl LinearSieve {
+    pub fn new(max_n: usize) -> Self {
+        let mut primes = vec![];
+        let mut spf = vec![0usize; max_n + 1];
+        let mut exp = vec![0u32; max_n + 1];
+        let mut pow = vec![1usize; max_n + 1];
+        for i in 2..=max_n {
+            if spf[i] == 0 {
+                spf[i] = i;
+                exp[i] = 1;
+                pow[i] = i;
+                primes.push(i);
+            }
+            for &p in primes.iter() {
+                let j = i * p;
+                if p > spf[i] || j > max_n {
+                    break;
+                }
+                spf[j] = p;
+                if p == spf[i] {
+                    exp[j] = exp[i] + 1;
+                    pow[j] = pow[i] * p;
+                } else {
+                    exp[j] = 1;
+                    pow[j] = p;
+                }
+            }
+        }
+        Self {
+            primes,
+            spf,
+            exp,
+            pow,
+        }
+    }
+    pub fn max_n(&self) -> usize {
+        self.spf.len() - 1
+    }
+    pub fn primes(&self) -> &[usize] {
+        &self.primes
+    }
+    pub fn is_prime(&self, n: usize) -> bool {
+        n >= 2 && self.spf[n] == n
+    }
+    /// smallest prime factor of `n >= 2`
+    pub fn spf(&self, n: usize) -> usize {
+        self.spf[n]
+    }
+    /// exponent of the smallest prime factor of `n >= 2`
+    pub fn spf_exp(&self, n: usize) -> u32 {
+        self.exp[n]
+    }
+    pub fn prime_factors(&self, mut n: usize) -> Vec<(usize, u32)> {
+        let mut factors = vec![];
+        while n > 1 {
+            factors.push((self.spf[n], self.exp[n]));
+            n /= self.pow[n];
+        }
+        factors
+    }
+    /// table of a multiplicative function on `[0, max_n]` in O(max_n), where `f(p, e)` is
+    /// $f(p^e)$ and the value at `0` is zero
+    pub fn multiplicative<T, F>(&self, mut f: F) -> Vec<T>
+    where
+        T: Clone + Zero + One + Mul<Output = T>,
+        F: FnMut(usize, u32) -> T,
+    {
+        let n = self.max_n();
+        let mut table = Vec::with_capacity(n + 1);
+        table.push(T::zero());
+        if n >= 1 {
+            table.push(T::one());
+        }
+        for i in 2..=n {
+            let x = if self.pow[i] == i {
+                f(self.spf[i], self.exp[i])
+            } else {
+                table[self.pow[i]].clone() * table[i / self.pow[i]].clone()
+            };
+            table.push(x);
+        }
+        table
+    }
+    pub fn euler_phi(&self) -> Vec<u64> {
+        self.multiplicative(|p, e| (p as u64 - 1) * (p as u64).pow(e - 1))
+    }
+    pub fn moebius(&self) -> Vec<i64> {
+        self.multiplicative(|_, e| if e == 1 { -1 } else { 0 })
+    }
+    /// number of divisors
+    pub fn divisor_count(&self) -> Vec<u64> {
+        self.multiplicative(|_, e| e as u64 + 1)
+    }
+    /// sum of `k`-th powers of divisors
+    pub fn divisor_sigma(&self, k: u32) -> Vec<u64> {
+        self.multiplicative(|p, e| {
+            let pk = (p as u64).pow(k);
+            (0..=e).fold((0u64, 1u64), |(s, x), _| (s + x, x * pk)).0
+        })
+    }
+    /// $g(n) = \sum_{d \mid n} f(d)$ in O(n log log n)
+    pub fn divisor_zeta<G>(&self, f: &mut [G::T])
+    where
+        G: AbelianMonoid,
+    {
+        let n = f.len() - 1;
+        assert!(n <= self.max_n());
+        for &p in self.primes.iter().take_while(|&&p| p <= n) {
+            for i in 1..=n / p {
+                f[i * p] = G::operate(&f[i * p], &f[i]);
+            }
+        }
+    }
+    /// inverse of [`divisor_zeta`](Self::divisor_zeta), Dirichlet convolution with Möbius
+    pub fn divisor_mobius<G>(&self, f: &mut [G::T])
+    where
+        G: AbelianGroup,
+    {
+        let n = f.len() - 1;
+        assert!(n <= self.max_n());
+        for &p in self.primes.iter().take_while(|&&p| p <= n) {
+            for i in (1..=n / p).rev() {
+                f[i * p] = G::rinv_operate(&f[i * p], &f[i]);
+            }
+        }
+    }
+    /// $g(n) = \sum_{n \mid m} f(m)$ in O(n log log n)
+    pub fn multiple_zeta<G>(&self, f: &mut [G::T])
+    where
+        G: AbelianMonoid,
+    {
+        let n = f.len() - 1;
+        assert!(n <= self.max_n());
+        for &p in self.primes.iter().take_while(|&&p| p <= n) {
+            for i in (1..=n / p).rev() {
+                f[i] = G::operate(&f[i], &f[i * p]);
+            }
+        }
+    }
+    /// inverse of [`multiple_zeta`](Self::multiple_zeta)
+    pub fn multiple_mobius<G>(&self, f: &mut [G::T])
+    where
+        G: AbelianGroup,
+    {
+        let n = f.len() - 1;
+        assert!(n <= self.max_n());
+        for &p in self.primes.iter().take_while(|&&p| p <= n) {
+            for i in 1..=n / p {
+                f[i] = G::rinv_operate(&f[i], &f[i * p]);
+            }
+        }
+    }
+}
+
+/// Dirichlet convolution of `f` and `g` on `[1, n]` in O(n log n), the value at `0` is zero
+pub fn dirichlet_convolution<R>(f: &[R::T], g: &[R::T]) -> Vec<R::T>
+where
+    R: SemiRing,
+{
+    let n = f.len().min(g.len()).saturating_sub(1);
+    let mut h = vec![R::zero(); n + 1];
+    for i in 1..=n {
+        for j in 1..=n / i {
+            h[i * j] = R::add(&h[i * j], &R::mul(&f[i], &g[j]));
+        }
+    }
+    h
+}
+
+/// Dirichlet inverse of `f` with `f(1) = 1` on `[1, n]` in O(n log n)
+pub fn dirichlet_inverse<R>(f: &[R::T]) -> Vec<R::T>
+where
+    R: Ring,
+    R::Additive: Invertible,
+{
+    let n = f.len().saturating_sub(1);
+    let mut g = vec![R::zero(); n + 1];
+    if n == 0 {
+        return g;
+    }
+    g[1] = R::one();
+    // g[i] is complete when reached, push its contribution to multiples
+    let mut acc = vec![R::zero(); n + 1];
+    for i in 1..=n {
+        if i > 1 {
+            g[i] = R::neg(&acc[i]);
+        }
+        for j in 2..=n / i {
+            acc[i * j] = R::add(&acc[i * j], &R::mul(&g[i], &f[j]));
+        }
+    }
+    g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algebra::{AddMulOperation, AdditiveOperation},
+        math::prime_factors,
+        num::mint_basic::MInt998244353,
+        rand,
+        tools::Xorshift,
+    };
+
+    #[test]
+    fn test_linear_sieve() {
+        const N: usize = 2000;
+        let sieve = LinearSieve::new(N);
+        let phi = sieve.euler_phi();
+        let mu = sieve.moebius();
+        let d = sieve.divisor_count();
+        let sigma = sieve.divisor_sigma(2);
+        for n in 1..=N {
+            let f = prime_factors(n as u64);
+            let g: Vec<_> = sieve
+                .prime_factors(n)
+                .into_iter()
+                .map(|(p, e)| (p as u64, e))
+                .collect();
+            assert_eq!(f, g);
+            assert_eq!(sieve.is_prime(n), f == [(n as u64, 1)]);
+            if n >= 2 {
+                assert_eq!(sieve.spf(n) as u64, f[0].0);
+                assert_eq!(sieve.spf_exp(n), f[0].1);
+            }
+            assert_eq!(phi[n], (1..=n).filter(|&k| gcd(k, n) == 1).count() as u64);
+            let expected_mu = if f.iter().any(|&(_, e)| e > 1) {
+                0
+            } else {
+                [1, -1][f.len() & 1]
+            };
+            assert_eq!(mu[n], expected_mu);
+            let divisors: Vec<_> = (1..=n as u64)
+                .filter(|&k| n as u64 / k * k == n as u64)
+                .collect();
+            assert_eq!(d[n], divisors.len() as u64);
+            assert_eq!(sigma[n], divisors.iter().map(|k| k * k).sum::<u64>());
+        }
+        assert_eq!(sieve.primes().len(), 303);
+    }
+
+    fn gcd(a: usize, b: usize) -> usize {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    #[test]
+    fn test_dirichlet() {
+        const N: usize = 300;
+        type R = AddMulOperation<MInt998244353>;
+        let mut rng = Xorshift::default();
+        let sieve = LinearSieve::new(N);
+        for _ in 0..20 {
+            rand!(rng, n: (1..=N), f: [0u32..100; n + 1], g: [0u32..100; n + 1]);
+            let f: Vec<_> = f.into_iter().map(MInt998244353::from).collect();
+            let g: Vec<_> = g.into_iter().map(MInt998244353::from).collect();
+            let h = dirichlet_convolution::<R>(&f, &g);
+            for (i, h) in h.iter().enumerate().skip(1) {
+                let expected: MInt998244353 = (1..=i)
+                    .filter(|&d| i / d * d == i)
+                    .map(|d| f[d] * g[i / d])
+                    .sum();
+                assert_eq!(*h, expected);
+            }
+
+            let mut f1 = f.clone();
+            f1[1] = MInt998244353::from(1u32);
+            let inv = dirichlet_inverse::<R>(&f1);
+            let e = dirichlet_convolution::<R>(&f1, &inv);
+            for (i, e) in e.iter().enumerate().skip(1) {
+                assert_eq!(*e, MInt998244353::from((i == 1) as u32));
+            }
+
+            let mut z = f.clone();
+            sieve.divisor_zeta::<AdditiveOperation<_>>(&mut z);
+            let ones = vec![MInt998244353::from(1u32); n + 1];
+            assert_eq!(z[1..], dirichlet_convolution::<R>(&f, &ones)[1..]);
+            sieve.divisor_mobius::<AdditiveOperation<_>>(&mut z);
+            assert_eq!(z[1..], f[1..]);
+
+            let mut z = f.clone();
+            sieve.multiple_zeta::<AdditiveOperation<_>>(&mut z);
+            for (i, z) in z.iter().enumerate().skip(1) {
+                let expected: MInt998244353 = (1..=n / i).map(|k| f[i * k]).sum();
+                assert_eq!(*z, expected);
+            }
+            sieve.multiple_mobius::<AdditiveOperation<_>>(&mut z);
+            assert_eq!(z[1..], f[1..]);
+        }
+        let mu = dirichlet_inverse::<AddMulOperation<i64>>(&vec![1i64; N + 1]);
+        assert_eq!(mu[1..], sieve.moebius()[1..]);
+    }
+}
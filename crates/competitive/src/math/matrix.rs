@@ -1,25 +1,46 @@
 use super::{One, Zero};
 use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
 
+/// dense matrix stored in row-major order
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Matrix<T> {
     pub shape: (usize, usize),
-    pub data: Vec<Vec<T>>,
+    /// `data[i * shape.1 + j]` is the entry at row `i` and column `j`
+    pub data: Vec<T>,
 }
 
 impl<T: Clone> Matrix<T> {
     pub fn new(shape: (usize, usize), z: T) -> Self {
         Self {
             shape,
-            data: vec![vec![z; shape.1]; shape.0],
+            data: vec![z; shape.0 * shape.1],
         }
     }
 }
 impl<T> Matrix<T> {
     pub fn from_vec(data: Vec<Vec<T>>) -> Self {
+        let shape = (data.len(), data.first().map(Vec::len).unwrap_or_default());
+        assert!(data.iter().all(|r| r.len() == shape.1));
         Self {
-            shape: (data.len(), data.first().map(Vec::len).unwrap_or_default()),
-            data,
+            shape,
+            data: data.into_iter().flatten().collect(),
+        }
+    }
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.shape.0).map(move |i| &self[i])
+    }
+    fn swap_rows(&mut self, i: usize, j: usize) {
+        if i != j {
+            let m = self.shape.1;
+            let (i, j) = (i.min(j), i.max(j));
+            let (x, y) = self.data.split_at_mut(j * m);
+            x[i * m..(i + 1) * m].swap_with_slice(&mut y[..m]);
+        }
+    }
+    fn swap_columns(&mut self, i: usize, j: usize) {
+        let m = self.shape.1;
+        for r in self.data.chunks_mut(m.max(1)) {
+            r.swap(i, j);
         }
     }
 }
@@ -30,7 +51,7 @@ where
     pub fn zeros(shape: (usize, usize)) -> Self {
         Self {
             shape,
-            data: vec![vec![Zero::zero(); shape.1]; shape.0],
+            data: vec![Zero::zero(); shape.0 * shape.1],
         }
     }
 }
@@ -39,33 +60,37 @@ where
     T: Clone + Zero + One,
 {
     pub fn eye(shape: (usize, usize)) -> Self {
-        let mut data = vec![vec![Zero::zero(); shape.1]; shape.0];
-        for (i, d) in data.iter_mut().enumerate() {
-            d[i] = One::one();
+        let mut res = Self::zeros(shape);
+        for i in 0..shape.0.min(shape.1) {
+            res[i][i] = One::one();
         }
-        Self { shape, data }
+        res
     }
 }
 impl<T> Index<usize> for Matrix<T> {
-    type Output = Vec<T>;
+    type Output = [T];
     fn index(&self, index: usize) -> &Self::Output {
-        &self.data[index]
+        let m = self.shape.1;
+        &self.data[index * m..(index + 1) * m]
     }
 }
 impl<T> IndexMut<usize> for Matrix<T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.data[index]
+        let m = self.shape.1;
+        &mut self.data[index * m..(index + 1) * m]
     }
 }
 impl<T> Index<(usize, usize)> for Matrix<T> {
     type Output = T;
     fn index(&self, index: (usize, usize)) -> &Self::Output {
-        &self.data[index.0][index.1]
+        assert!(index.1 < self.shape.1);
+        &self.data[index.0 * self.shape.1 + index.1]
     }
 }
 impl<T> IndexMut<(usize, usize)> for Matrix<T> {
     fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
-        &mut self.data[index.0][index.1]
+        assert!(index.1 < self.shape.1);
+        &mut self.data[index.0 * self.shape.1 + index.1]
     }
 }
 impl<'a, T> Add for &'a Matrix<T>
@@ -105,13 +130,18 @@ where
     T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
 {
     type Output = Matrix<T>;
+    /// accumulates rows of `rhs` in i-k-j order over the flat storage
     fn mul(self, rhs: Self) -> Self::Output {
         assert_eq!(self.shape.1, rhs.shape.0);
-        let mut res = Matrix::zeros((self.shape.0, rhs.shape.1));
-        for i in 0..self.shape.0 {
-            for j in 0..rhs.shape.1 {
-                for k in 0..self.shape.1 {
-                    res[i][j] = res[i][j] + self[i][k] * rhs[k][j];
+        let (n, l, m) = (self.shape.0, self.shape.1, rhs.shape.1);
+        let mut res = Matrix::zeros((n, m));
+        if l == 0 || m == 0 {
+            return res;
+        }
+        for (c, a) in res.data.chunks_mut(m).zip(self.data.chunks(l)) {
+            for (a, b) in a.iter().zip(rhs.data.chunks(m)) {
+                for (c, b) in c.iter_mut().zip(b) {
+                    *c = *c + *a * *b;
                 }
             }
         }
@@ -149,7 +179,7 @@ where
                     return;
                 }
                 if let Some(pivot) = (r..n).find(|&p| !self[p][c].is_zero()) {
-                    self.data.swap(r, pivot);
+                    self.swap_rows(r, pivot);
                     break;
                 };
                 c += 1;
@@ -176,15 +206,30 @@ where
         let n = self.shape.0;
         self.row_reduction(false);
         (0..n)
-            .filter(|&i| !self.data[i].iter().all(|x| x.is_zero()))
+            .filter(|&i| !self[i].iter().all(|x| x.is_zero()))
             .count()
     }
     pub fn determinant(&mut self) -> T {
         assert_eq!(self.shape.0, self.shape.1);
-        self.row_reduction(false);
+        let n = self.shape.0;
         let mut d = T::one();
-        for i in 0..self.shape.0 {
-            d = d * self[i][i];
+        for c in 0..n {
+            let pivot = match (c..n).find(|&p| !self[p][c].is_zero()) {
+                Some(pivot) => pivot,
+                None => return T::zero(),
+            };
+            if pivot != c {
+                self.swap_rows(c, pivot);
+                d = T::zero() - d;
+            }
+            d = d * self[c][c];
+            let inv = T::one() / self[c][c];
+            for i in c + 1..n {
+                let e = self[i][c] * inv;
+                for j in c..n {
+                    self[i][j] = self[i][j] - e * self[c][j];
+                }
+            }
         }
         d
     }
@@ -212,6 +257,52 @@ where
         }
         Some(x)
     }
+    /// a solution of $Ax = b$ and a basis of the kernel of $A$
+    pub fn solve_system_of_linear_equations_with_kernel(
+        &self,
+        b: &[T],
+    ) -> Option<(Vec<T>, Vec<Vec<T>>)> {
+        assert_eq!(self.shape.0, b.len());
+        let (n, m) = self.shape;
+        let mut c = Matrix::<T>::zeros((n, m + 1));
+        for i in 0..n {
+            c[i][..m].clone_from_slice(&self[i]);
+            c[i][m] = b[i];
+        }
+        c.row_reduction(true);
+        let mut x = vec![T::zero(); m];
+        let mut pivots = vec![];
+        for i in 0..n {
+            match (0..=m).find(|&j| !c[i][j].is_zero()) {
+                Some(j) if j == m => return None,
+                Some(j) => {
+                    x[j] = c[i][m];
+                    pivots.push(j);
+                }
+                None => break,
+            }
+        }
+        let mut is_pivot = vec![false; m];
+        for &j in pivots.iter() {
+            is_pivot[j] = true;
+        }
+        let mut basis = vec![];
+        for f in (0..m).filter(|&f| !is_pivot[f]) {
+            let mut v = vec![T::zero(); m];
+            v[f] = T::one();
+            for (i, &j) in pivots.iter().enumerate() {
+                v[j] = T::zero() - c[i][f];
+            }
+            basis.push(v);
+        }
+        Some((x, basis))
+    }
+    pub fn kernel_basis(&self) -> Vec<Vec<T>> {
+        let b = vec![T::zero(); self.shape.0];
+        self.solve_system_of_linear_equations_with_kernel(&b)
+            .map(|(_, basis)| basis)
+            .unwrap_or_default()
+    }
     pub fn inverse(&self) -> Option<Matrix<T>> {
         assert_eq!(self.shape.0, self.shape.1);
         let n = self.shape.0;
@@ -224,10 +315,73 @@ where
         if (0..n).any(|i| c[i][i].is_zero()) {
             None
         } else {
-            Some(Self::from_vec(
-                c.data.into_iter().map(|r| r[n..].to_vec()).collect(),
-            ))
+            Some(Self {
+                shape: (n, n),
+                data: c.rows().flat_map(|r| r[n..].iter().copied()).collect(),
+            })
+        }
+    }
+}
+impl<T> Matrix<T>
+where
+    T: Copy
+        + PartialEq
+        + Zero
+        + One
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    /// characteristic polynomial $\det(xI - A)$ by Hessenberg reduction in $O(n^3)$
+    pub fn characteristic_polynomial(&self) -> Vec<T> {
+        assert_eq!(self.shape.0, self.shape.1);
+        let n = self.shape.0;
+        let mut a = self.clone();
+        for j in 0..n.saturating_sub(2) {
+            let pivot = match (j + 1..n).find(|&i| !a[i][j].is_zero()) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            if pivot != j + 1 {
+                a.swap_rows(pivot, j + 1);
+                a.swap_columns(pivot, j + 1);
+            }
+            let inv = T::one() / a[j + 1][j];
+            for i in j + 2..n {
+                let e = a[i][j] * inv;
+                if e.is_zero() {
+                    continue;
+                }
+                for k in j..n {
+                    a[i][k] = a[i][k] - e * a[j + 1][k];
+                }
+                for r in a.data.chunks_mut(n) {
+                    r[j + 1] = r[j + 1] + e * r[i];
+                }
+            }
         }
+        // characteristic polynomials of leading principal submatrices
+        let mut p: Vec<Vec<T>> = vec![vec![T::one()]];
+        for i in 0..n {
+            let mut q = vec![T::zero(); i + 2];
+            for (k, &c) in p[i].iter().enumerate() {
+                q[k + 1] = q[k + 1] + c;
+                q[k] = q[k] - a[i][i] * c;
+            }
+            let mut t = T::one();
+            for j in (0..i).rev() {
+                t = t * a[j + 1][j];
+                let e = t * a[j][i];
+                if !e.is_zero() {
+                    for (q, &c) in q.iter_mut().zip(&p[j]) {
+                        *q = *q - e * c;
+                    }
+                }
+            }
+            p.push(q);
+        }
+        p.pop().unwrap()
     }
 }
 
@@ -235,8 +389,8 @@ where
 mod tests {
     use super::*;
     use crate::{
-        num::mint_basic::DynMIntU32,
-        rand_value,
+        num::mint_basic::{DynMIntU32, MInt998244353},
+        rand, rand_value,
         tools::{RandomSpec, Xorshift},
     };
     struct D;
@@ -264,4 +418,145 @@ mod tests {
             }
         }
     }
+
+    type M = MInt998244353;
+
+    fn random_matrix(rng: &mut Xorshift, shape: (usize, usize), q: u32) -> Matrix<M> {
+        let mut a = Matrix::zeros(shape);
+        for i in 0..shape.0 {
+            for j in 0..shape.1 {
+                a[i][j] = M::from(rng.gen(0..q));
+            }
+        }
+        a
+    }
+
+    fn cofactor_determinant(a: &Matrix<M>) -> M {
+        let n = a.shape.0;
+        if n == 0 {
+            return M::one();
+        }
+        let mut d = M::zero();
+        for j in 0..n {
+            let minor = Matrix::from_vec(
+                (1..n)
+                    .map(|i| (0..n).filter(|&k| k != j).map(|k| a[i][k]).collect())
+                    .collect(),
+            );
+            let c = a[0][j] * cofactor_determinant(&minor);
+            if j & 1 == 0 {
+                d += c;
+            } else {
+                d -= c;
+            }
+        }
+        d
+    }
+
+    #[test]
+    fn test_mul() {
+        let mut rng = Xorshift::default();
+        for _ in 0..100 {
+            rand!(rng, n: (0..10), l: (0..10), m: (0..10));
+            let a = random_matrix(&mut rng, (n, l), 100);
+            let b = random_matrix(&mut rng, (l, m), 100);
+            let c = &a * &b;
+            assert_eq!(c.shape, (n, m));
+            for i in 0..n {
+                for j in 0..m {
+                    assert_eq!(c[i][j], (0..l).map(|k| a[i][k] * b[k][j]).sum());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_determinant_sign() {
+        let mut a = Matrix::from_vec(vec![vec![M::zero(), M::one()], vec![M::one(), M::zero()]]);
+        assert_eq!(a.determinant(), -M::one());
+        let mut rng = Xorshift::default();
+        for _ in 0..100 {
+            rand!(rng, n: (1..10));
+            let mut perm: Vec<usize> = (0..n).collect();
+            rng.shuffle(&mut perm);
+            let mut a = Matrix::zeros((n, n));
+            for (i, &j) in perm.iter().enumerate() {
+                a[i][j] = M::from(2u32);
+            }
+            let inversions = (0..n)
+                .flat_map(|i| (0..i).map(move |j| (j, i)))
+                .filter(|&(j, i)| perm[j] > perm[i])
+                .count();
+            let expected = M::from(2u32).pow(n)
+                * if inversions & 1 == 1 {
+                    -M::one()
+                } else {
+                    M::one()
+                };
+            assert_eq!(a.determinant(), expected);
+        }
+    }
+
+    #[test]
+    fn test_characteristic_polynomial() {
+        let mut rng = Xorshift::default();
+        for _ in 0..200 {
+            rand!(rng, n: (0..7), q: (1..4));
+            let a = random_matrix(&mut rng, (n, n), q);
+            let p = a.characteristic_polynomial();
+            assert_eq!(p.len(), n + 1);
+            let det = cofactor_determinant(&a);
+            assert_eq!(a.clone().determinant(), det);
+            assert_eq!(p[0], if n & 1 == 0 { det } else { -det });
+            let x = M::from(rng.gen(0..1000u32));
+            let mut b = Matrix::zeros((n, n));
+            for i in 0..n {
+                for j in 0..n {
+                    b[i][j] = M::from((i == j) as u32) * x - a[i][j];
+                }
+            }
+            let expected = p.iter().rev().fold(M::zero(), |acc, &c| acc * x + c);
+            assert_eq!(cofactor_determinant(&b), expected);
+        }
+    }
+
+    #[test]
+    fn test_kernel() {
+        let mut rng = Xorshift::default();
+        for _ in 0..200 {
+            rand!(rng, n: (1..10), m: (1..10), q: (1..4));
+            let a = random_matrix(&mut rng, (n, m), q);
+            let b: Vec<_> = if rng.gen_bool(0.5) {
+                let x = random_matrix(&mut rng, (m, 1), q);
+                (&a * &x).data
+            } else {
+                (0..n).map(|_| M::from(rng.gen(0..q))).collect()
+            };
+            let apply = |x: &[M]| -> Vec<M> {
+                (0..n)
+                    .map(|i| (0..m).map(|j| a[i][j] * x[j]).sum())
+                    .collect()
+            };
+            let rank = a.clone().rank();
+            let basis = a.kernel_basis();
+            assert_eq!(basis.len(), m - rank);
+            for v in basis.iter() {
+                assert_eq!(apply(v), vec![M::zero(); n]);
+            }
+            match a.solve_system_of_linear_equations_with_kernel(&b) {
+                Some((x, kernel)) => {
+                    assert_eq!(apply(&x), b);
+                    assert_eq!(kernel, basis);
+                }
+                None => {
+                    let mut c = Matrix::zeros((n, m + 1));
+                    for i in 0..n {
+                        c[i][..m].copy_from_slice(&a[i]);
+                        c[i][m] = b[i];
+                    }
+                    assert_eq!(c.rank(), rank + 1);
+                }
+            }
+        }
+    }
 }
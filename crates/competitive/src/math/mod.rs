@@ -5,29 +5,42 @@ use crate::tools::{AssociatedValue, PartialIgnoredOrd, Xorshift};
 
 #[codesnip::entry("berlekamp_massey")]
 pub use self::berlekamp_massey::berlekamp_massey;
+#[codesnip::entry("BivariateFormalPowerSeries")]
+pub use self::bivariate_formal_power_series::BivariateFormalPowerSeries;
 #[codesnip::entry("ConvolveSteps")]
-pub use self::convolve_steps::ConvolveSteps;
+pub use self::convolve_steps::{
+    convolve_karatsuba, convolve_naive, convolve_with_fallback, ConvolveSteps,
+    CONVOLVE_FALLBACK_THRESHOLD,
+};
 #[codesnip::entry("discrete_logarithm")]
 pub use self::discrete_logarithm::{discrete_logarithm, discrete_logarithm_prime_mod};
 pub use self::factorial::*;
 #[codesnip::entry("fast_fourier_transform")]
-pub use self::fast_fourier_transform::ConvolveRealFft;
+pub use self::fast_fourier_transform::{ConvolveRealFft, MIntConvolveFft};
+#[codesnip::entry("FloorQuotients")]
+pub use self::floor_quotients::{prime_count, FloorQuotients};
 #[codesnip::entry("floor_sum")]
 pub use self::floor_sum::{floor_sum, floor_sum_i64, floor_sum_range_freq};
 #[codesnip::entry("FormalPowerSeries")]
 pub use self::formal_power_series::{
     FormalPowerSeries, FormalPowerSeriesCoefficient, FormalPowerSeriesCoefficientSqrt, Fps,
-    Fps998244353,
+    Fps998244353, SubproductTree,
 };
 pub use self::gcd::*;
+#[codesnip::entry("Gf2Matrix")]
+pub use self::gf2_matrix::Gf2Matrix;
 #[codesnip::entry("lagrange_interpolation")]
 pub use self::lagrange_interpolation::{lagrange_interpolation, lagrange_interpolation_polynomial};
+#[codesnip::entry("LinearSieve")]
+pub use self::linear_sieve::{dirichlet_convolution, dirichlet_inverse, LinearSieve};
 #[codesnip::entry("Matrix")]
 pub use self::matrix::Matrix;
 #[codesnip::entry("miller_rabin")]
 pub use self::miller_rabin::{miller_rabin, miller_rabin_with_br};
 #[codesnip::entry("NumberTheoreticTransform")]
-pub use self::number_theoretic_transform::{Convolve, Convolve998244353, MIntConvolve};
+pub use self::number_theoretic_transform::{
+    convolve_i64, convolve_u64, Convolve, Convolve998244353, MIntConvolve,
+};
 pub use self::nums::*;
 pub use self::polynomial::*;
 pub use self::prime::*;
@@ -39,10 +52,17 @@ pub use self::prime_list::PrimeList;
 pub use self::prime_table::PrimeTable;
 #[codesnip::entry("primitive_root")]
 pub use self::primitive_root::{check_primitive_root, primitive_root};
+#[codesnip::entry("SparseMatrix")]
+pub use self::sparse_matrix::SparseMatrix;
 
 #[cfg_attr(nightly, codesnip::entry("berlekamp_massey", include("zero_one")))]
 mod berlekamp_massey;
-#[cfg_attr(nightly, codesnip::entry("ConvolveSteps"))]
+#[cfg_attr(
+    nightly,
+    codesnip::entry("BivariateFormalPowerSeries", include("FormalPowerSeries"))
+)]
+mod bivariate_formal_power_series;
+#[cfg_attr(nightly, codesnip::entry("ConvolveSteps", include("zero_one")))]
 mod convolve_steps;
 #[cfg_attr(
     nightly,
@@ -64,10 +84,15 @@ mod factorial;
     nightly,
     codesnip::entry(
         "fast_fourier_transform",
-        include("Complex", "AssociatedValue", "ConvolveSteps")
+        include("Complex", "AssociatedValue", "ConvolveSteps", "MIntBase")
     )
 )]
 mod fast_fourier_transform;
+#[cfg_attr(
+    nightly,
+    codesnip::entry("FloorQuotients", include("PrimeList", "zero_one"))
+)]
+mod floor_quotients;
 #[cfg_attr(nightly, codesnip::entry("floor_sum", include("BarrettReduction")))]
 mod floor_sum;
 #[cfg_attr(
@@ -80,17 +105,25 @@ mod floor_sum;
             "mod_sqrt",
             "factorial",
             "PartialIgnoredOrd",
-            "berlekamp_massey"
+            "berlekamp_massey",
+            "BivariateFormalPowerSeries"
         )
     )
 )]
 mod formal_power_series;
 mod gcd;
+#[cfg_attr(nightly, codesnip::entry("Gf2Matrix", include("BitSet")))]
+mod gf2_matrix;
 #[cfg_attr(
     nightly,
     codesnip::entry("lagrange_interpolation", include("factorial", "MIntBase"))
 )]
 mod lagrange_interpolation;
+#[cfg_attr(
+    nightly,
+    codesnip::entry("LinearSieve", include("algebra", "ring", "zero_one"))
+)]
+mod linear_sieve;
 #[cfg_attr(nightly, codesnip::entry("Matrix", include("zero_one")))]
 mod matrix;
 #[cfg_attr(nightly, codesnip::entry("miller_rabin", include("BarrettReduction")))]
@@ -119,3 +152,8 @@ mod prime_list;
 mod prime_table;
 #[cfg_attr(nightly, codesnip::entry("primitive_root", include("prime_factors")))]
 mod primitive_root;
+#[cfg_attr(
+    nightly,
+    codesnip::entry("SparseMatrix", include("berlekamp_massey", "MIntBase", "Xorshift"))
+)]
+mod sparse_matrix;
@@ -1,5 +1,6 @@
 use super::{
-    montgomery::*, AssociatedValue, ConvolveSteps, MInt, MIntBase, MIntConvert, One, Zero,
+    convolve_with_fallback, montgomery::*, AssociatedValue, ConvolveSteps, MInt, MIntBase,
+    MIntConvert, One, Zero, CONVOLVE_FALLBACK_THRESHOLD,
 };
 use std::marker::PhantomData;
 
@@ -161,6 +162,9 @@ where
             *f *= *g;
         }
     }
+    fn convolve(a: Self::T, b: Self::T) -> Self::T {
+        convolve_with_fallback::<Self, _>(a, b)
+    }
 }
 type MVec<M> = Vec<MInt<M>>;
 impl<M, N1, N2, N3> ConvolveSteps for Convolve<(M, (N1, N2, N3))>
@@ -228,6 +232,118 @@ where
             *f *= *g;
         }
     }
+    fn convolve(a: Self::T, b: Self::T) -> Self::T {
+        convolve_with_fallback::<Self, _>(a, b)
+    }
+}
+
+/// convolutions of (high, low) 32 bits as (high * high, high * low + low * high, low * low)
+fn convolve_split32<N>(a: &[u64], b: &[u64], len: usize) -> [Vec<MInt<N>>; 3]
+where
+    N: NttModulus,
+{
+    let split = |a: &[u64]| {
+        let hi = a
+            .iter()
+            .map(|&x| MInt::<N>::from((x >> 32) as u32))
+            .collect();
+        let lo = a.iter().map(|&x| MInt::<N>::from(x as u32)).collect();
+        (
+            Convolve::<N>::transform(hi, len),
+            Convolve::<N>::transform(lo, len),
+        )
+    };
+    let (ah, al) = split(a);
+    let (bh, bl) = split(b);
+    let mid = ah
+        .iter()
+        .zip(&al)
+        .zip(bh.iter().zip(&bl))
+        .map(|((&ah, &al), (&bh, &bl))| ah * bl + al * bh)
+        .collect();
+    let mut hh = ah;
+    Convolve::<N>::multiply(&mut hh, &bh);
+    let mut ll = al;
+    Convolve::<N>::multiply(&mut ll, &bl);
+    [
+        Convolve::<N>::inverse_transform(hh, len),
+        Convolve::<N>::inverse_transform(mid, len),
+        Convolve::<N>::inverse_transform(ll, len),
+    ]
+}
+
+/// exact convolution of `u64` sequences if all results fit in `u128`, wrapping otherwise
+pub fn convolve_u64(a: &[u64], b: &[u64]) -> Vec<u128> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let len = a.len() + b.len() - 1;
+    if a.len().min(b.len()) <= CONVOLVE_FALLBACK_THRESHOLD {
+        let mut c = vec![0u128; len];
+        for (i, &a) in a.iter().enumerate() {
+            for (c, &b) in c[i..].iter_mut().zip(b) {
+                *c = c.wrapping_add(a as u128 * b as u128);
+            }
+        }
+        return c;
+    }
+    type N1 = Modulo2013265921;
+    type N2 = Modulo1811939329;
+    type N3 = Modulo2113929217;
+    let [h1, m1, l1] = convolve_split32::<N1>(a, b, len);
+    let [h2, m2, l2] = convolve_split32::<N2>(a, b, len);
+    let [h3, m3, l3] = convolve_split32::<N3>(a, b, len);
+    let (p1, p2) = (<N1 as MIntBase>::get_mod(), <N2 as MIntBase>::get_mod());
+    let t1 = MInt::<N2>::new(p1).inv();
+    let t2 = (MInt::<N3>::new(p1) * MInt::<N3>::new(p2)).inv();
+    let garner = |c1: MInt<N1>, c2: MInt<N2>, c3: MInt<N3>| {
+        let d1 = c1.inner();
+        let d2 = ((c2 - MInt::<N2>::from(d1)) * t1).inner();
+        let x = MInt::<N3>::new(d1) + MInt::<N3>::new(d2) * MInt::<N3>::new(p1);
+        let d3 = ((c3 - x) * t2).inner();
+        d1 as u128 + d2 as u128 * p1 as u128 + d3 as u128 * p1 as u128 * p2 as u128
+    };
+    (0..len)
+        .map(|i| {
+            let h = garner(h1[i], h2[i], h3[i]);
+            let m = garner(m1[i], m2[i], m3[i]);
+            let l = garner(l1[i], l2[i], l3[i]);
+            (h << 64).wrapping_add(m << 32).wrapping_add(l)
+        })
+        .collect()
+}
+
+/// exact convolution of `i64` sequences if all results fit in `i128`, wrapping otherwise
+pub fn convolve_i64(a: &[i64], b: &[i64]) -> Vec<i128> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    // (a + 2^63) * (b + 2^63) with corrections by sliding window sums
+    const K: u128 = 1 << 63;
+    let biased = |a: &[i64]| -> Vec<u64> { a.iter().map(|&x| x as u64 ^ K as u64).collect() };
+    let (ua, ub) = (biased(a), biased(b));
+    let prefix = |a: &[u64]| -> Vec<u128> {
+        let mut s = vec![0u128; a.len() + 1];
+        for (i, &x) in a.iter().enumerate() {
+            s[i + 1] = s[i].wrapping_add(x as u128);
+        }
+        s
+    };
+    let (sa, sb) = (prefix(&ua), prefix(&ub));
+    let (n, m) = (a.len(), b.len());
+    convolve_u64(&ua, &ub)
+        .into_iter()
+        .enumerate()
+        .map(|(k, c)| {
+            // a_i b_{k-i} for i in [l, r)
+            let (l, r) = ((k + 1).saturating_sub(m), (k + 1).min(n));
+            let wa = sa[r].wrapping_sub(sa[l]);
+            let wb = sb[k + 1 - l].wrapping_sub(sb[k + 1 - r]);
+            let cnt = (r - l) as u128;
+            c.wrapping_sub(K.wrapping_mul(wa.wrapping_add(wb)))
+                .wrapping_add((K * K).wrapping_mul(cnt)) as i128
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -288,6 +404,36 @@ mod tests {
         assert_eq!(c, d);
     }
 
+    #[test]
+    fn test_convolve_u64_i64() {
+        let mut rng = Xorshift::default();
+        for _ in 0..50 {
+            let n = rng.gen(1..300);
+            let m = rng.gen(1..300);
+            let shift = rng.gen(0..64u32);
+            let a: Vec<u64> = (0..n).map(|_| rng.rand64() >> shift).collect();
+            let b: Vec<u64> = (0..m).map(|_| rng.rand64() >> shift).collect();
+            let mut c = vec![0u128; n + m - 1];
+            for i in 0..n {
+                for j in 0..m {
+                    c[i + j] = c[i + j].wrapping_add(a[i] as u128 * b[j] as u128);
+                }
+            }
+            assert_eq!(convolve_u64(&a, &b), c);
+
+            let a: Vec<i64> = a.iter().map(|&x| x as i64 >> rng.gen(0..64u32)).collect();
+            let b: Vec<i64> = b.iter().map(|&x| x as i64 >> rng.gen(0..64u32)).collect();
+            let mut c = vec![0i128; n + m - 1];
+            for i in 0..n {
+                for j in 0..m {
+                    c[i + j] = c[i + j].wrapping_add(a[i] as i128 * b[j] as i128);
+                }
+            }
+            assert_eq!(convolve_i64(&a, &b), c);
+        }
+        assert!(convolve_u64(&[], &[1]).is_empty());
+    }
+
     // #[test]
     #[allow(dead_code)]
     fn find_proth() {
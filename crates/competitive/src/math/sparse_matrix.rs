@@ -0,0 +1,186 @@
+use super::{berlekamp_massey, MInt, MIntConvert, One, Xorshift, Zero};
+use std::ops::{Add, Mul};
+
+/// sparse matrix as a list of nonzero entries for black-box algorithms
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseMatrix<T> {
+    pub shape: (usize, usize),
+    pub nonzero: Vec<(usize, usize, T)>,
+}
+
+impl<T> SparseMatrix<T> {
+    pub fn new(shape: (usize, usize)) -> Self {
+        Self {
+            shape,
+            nonzero: vec![],
+        }
+    }
+    pub fn from_nonzero(shape: (usize, usize), nonzero: Vec<(usize, usize, T)>) -> Self {
+        Self { shape, nonzero }
+    }
+    pub fn push(&mut self, i: usize, j: usize, x: T) {
+        assert!(i < self.shape.0 && j < self.shape.1);
+        self.nonzero.push((i, j, x));
+    }
+}
+
+impl<T> SparseMatrix<T>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    /// $Av$ in $O(\mathrm{nnz})$
+    pub fn apply(&self, v: &[T]) -> Vec<T> {
+        assert_eq!(self.shape.1, v.len());
+        let mut res = vec![T::zero(); self.shape.0];
+        for &(i, j, x) in self.nonzero.iter() {
+            res[i] = res[i] + x * v[j];
+        }
+        res
+    }
+}
+
+impl<M> SparseMatrix<MInt<M>>
+where
+    M: MIntConvert<u64>,
+{
+    fn random_vec(rng: &mut Xorshift, n: usize) -> Vec<MInt<M>> {
+        (0..n).map(|_| MInt::from(rng.rand64())).collect()
+    }
+    /// minimal polynomial of $u^\top A^k v$ by Wiedemann's algorithm
+    fn projected_minimal_polynomial(&self, u: &[MInt<M>], v: Vec<MInt<M>>) -> Vec<MInt<M>> {
+        let n = self.shape.0;
+        let mut v = v;
+        let mut a = Vec::with_capacity(n * 2);
+        for _ in 0..n * 2 {
+            a.push(u.iter().zip(&v).map(|(&u, &v)| u * v).sum());
+            v = self.apply(&v);
+        }
+        berlekamp_massey(&a)
+    }
+    /// minimal polynomial of $A$ in $O(n(n + \mathrm{nnz}))$, correct with high probability
+    ///
+    /// Coefficients are in descending order from the leading $1$.
+    pub fn minimal_polynomial(&self) -> Vec<MInt<M>> {
+        assert_eq!(self.shape.0, self.shape.1);
+        let mut rng = Xorshift::default();
+        let u = Self::random_vec(&mut rng, self.shape.0);
+        let v = Self::random_vec(&mut rng, self.shape.0);
+        self.projected_minimal_polynomial(&u, v)
+    }
+    /// determinant in $O(n(n + \mathrm{nnz}))$, correct with high probability
+    pub fn determinant(&self) -> MInt<M> {
+        assert_eq!(self.shape.0, self.shape.1);
+        let n = self.shape.0;
+        let mut rng = Xorshift::default();
+        // minimal polynomial of AD equals its characteristic polynomial for random diagonal D
+        let d: Vec<MInt<M>> = (0..n)
+            .map(|_| loop {
+                let x = MInt::from(rng.rand64());
+                if !x.is_zero() {
+                    break x;
+                }
+            })
+            .collect();
+        let ad = SparseMatrix::from_nonzero(
+            self.shape,
+            self.nonzero
+                .iter()
+                .map(|&(i, j, x)| (i, j, x * d[j]))
+                .collect(),
+        );
+        let u = Self::random_vec(&mut rng, n);
+        let v = Self::random_vec(&mut rng, n);
+        let c = ad.projected_minimal_polynomial(&u, v);
+        if c.len() != n + 1 {
+            return MInt::zero();
+        }
+        let det = c[n] / d.iter().fold(MInt::one(), |acc, &d| acc * d);
+        if n & 1 == 1 {
+            -det
+        } else {
+            det
+        }
+    }
+    /// solution of $Ax = b$ for nonsingular $A$ in $O(n(n + \mathrm{nnz}))$
+    ///
+    /// Returns `None` when $A$ seems singular.
+    pub fn solve_system_of_linear_equations(&self, b: &[MInt<M>]) -> Option<Vec<MInt<M>>> {
+        assert_eq!(self.shape.0, self.shape.1);
+        let n = self.shape.0;
+        let mut rng = Xorshift::default();
+        let u = Self::random_vec(&mut rng, n);
+        // q(A)b = 0 where q(x) = \sum_j c_j x^{l-j}
+        let c = self.projected_minimal_polynomial(&u, b.to_vec());
+        let l = c.len() - 1;
+        if c[l].is_zero() {
+            return None;
+        }
+        let mut y = vec![MInt::zero(); n];
+        for &c in &c[..l] {
+            y = self.apply(&y);
+            for (y, &b) in y.iter_mut().zip(b) {
+                *y += c * b;
+            }
+        }
+        let k = -c[l].inv();
+        let x: Vec<_> = y.into_iter().map(|y| y * k).collect();
+        if self.apply(&x) == b {
+            Some(x)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{math::Matrix, num::montgomery::MInt998244353, rand};
+
+    type M = MInt998244353;
+
+    fn random_sparse(rng: &mut Xorshift, n: usize, k: usize) -> SparseMatrix<M> {
+        let mut a = SparseMatrix::new((n, n));
+        for _ in 0..k {
+            rand!(rng, i: (0..n), j: (0..n), x: (0..100u32));
+            a.push(i, j, M::from(x));
+        }
+        a
+    }
+
+    fn to_dense(a: &SparseMatrix<M>) -> Matrix<M> {
+        let mut b = Matrix::zeros(a.shape);
+        for &(i, j, x) in a.nonzero.iter() {
+            b[i][j] += x;
+        }
+        b
+    }
+
+    #[test]
+    fn test_sparse_matrix() {
+        let mut rng = Xorshift::default();
+        for _ in 0..200 {
+            rand!(rng, n: (1..30), k: (0..n * 4));
+            let a = random_sparse(&mut rng, n, k);
+            let dense = to_dense(&a);
+            let det = dense.clone().determinant();
+            assert_eq!(a.determinant(), det);
+
+            let p = a.minimal_polynomial();
+            let mut q = Matrix::zeros((n, n));
+            for &c in p.iter() {
+                q = &q * &dense;
+                for i in 0..n {
+                    q[i][i] += c;
+                }
+            }
+            assert_eq!(q, Matrix::zeros((n, n)));
+
+            let b: Vec<_> = (0..n).map(|_| M::from(rng.gen(0..100u32))).collect();
+            match a.solve_system_of_linear_equations(&b) {
+                Some(x) => assert_eq!(a.apply(&x), b),
+                None => assert_eq!(det, M::zero()),
+            }
+        }
+    }
+}
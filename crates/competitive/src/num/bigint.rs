@@ -0,0 +1,904 @@
+#![allow(clippy::manual_strip, clippy::legacy_numeric_constants)]
+
+use super::{
+    montgomery::{Modulo1811939329, Modulo2013265921},
+    IterScan, MInt, MIntConvert, One, Zero,
+};
+use crate::math::{Convolve, ConvolveSteps};
+use std::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt::{self, Debug, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+const BASE: u32 = 1_000_000_000;
+const BASE_DIGITS: usize = 9;
+const KARATSUBA_THRESHOLD: usize = 32;
+const NTT_THRESHOLD: usize = 256;
+
+/// limbs are little endian in base $10^9$ and may have leading zeros
+mod limbs {
+    use super::*;
+
+    pub fn trim(a: &mut Vec<u32>) {
+        while a.last() == Some(&0) {
+            a.pop();
+        }
+    }
+    /// `a` and `b` must be trimmed
+    pub fn cmp(a: &[u32], b: &[u32]) -> Ordering {
+        a.len()
+            .cmp(&b.len())
+            .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+    }
+    pub fn add(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+        let mut c = Vec::with_capacity(a.len() + 1);
+        let mut carry = 0;
+        for (i, &x) in a.iter().enumerate() {
+            let mut s = x + b.get(i).cloned().unwrap_or_default() + carry;
+            carry = (s >= BASE) as u32;
+            if carry == 1 {
+                s -= BASE;
+            }
+            c.push(s);
+        }
+        if carry == 1 {
+            c.push(1);
+        }
+        c
+    }
+    /// `a += b << (shift * 9)`
+    pub fn add_shifted(a: &mut Vec<u32>, b: &[u32], shift: usize) {
+        if a.len() < shift + b.len() {
+            a.resize(shift + b.len(), 0);
+        }
+        let mut carry = 0;
+        let mut i = shift;
+        while i < shift + b.len() || carry == 1 {
+            if i == a.len() {
+                a.push(0);
+            }
+            let s = a[i] + b.get(i - shift).cloned().unwrap_or_default() + carry;
+            carry = (s >= BASE) as u32;
+            a[i] = if carry == 1 { s - BASE } else { s };
+            i += 1;
+        }
+    }
+    /// `a -= b` where `a >= b`
+    pub fn sub_assign(a: &mut [u32], b: &[u32]) {
+        let mut borrow = 0;
+        for (i, x) in a.iter_mut().enumerate() {
+            if i >= b.len() && borrow == 0 {
+                break;
+            }
+            let s = b.get(i).cloned().unwrap_or_default() + borrow;
+            if *x >= s {
+                *x -= s;
+                borrow = 0;
+            } else {
+                *x = *x + BASE - s;
+                borrow = 1;
+            }
+        }
+        debug_assert_eq!(borrow, 0);
+    }
+    pub fn mul_small(a: &[u32], k: u32) -> Vec<u32> {
+        let mut c = Vec::with_capacity(a.len() + 1);
+        let mut carry = 0u64;
+        for &x in a {
+            let p = x as u64 * k as u64 + carry;
+            c.push((p % BASE as u64) as u32);
+            carry = p / BASE as u64;
+        }
+        if carry > 0 {
+            c.push(carry as u32);
+        }
+        c
+    }
+    pub fn divrem_small(a: &[u32], k: u32) -> (Vec<u32>, u32) {
+        let mut q = vec![0u32; a.len()];
+        let mut r = 0u64;
+        for (q, &x) in q.iter_mut().zip(a).rev() {
+            let cur = r * BASE as u64 + x as u64;
+            *q = (cur / k as u64) as u32;
+            r = cur % k as u64;
+        }
+        trim(&mut q);
+        (q, r as u32)
+    }
+    pub fn mul_schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+        if a.is_empty() || b.is_empty() {
+            return vec![];
+        }
+        let base = BASE as u64;
+        let mut c = vec![0u64; a.len() + b.len()];
+        for (i, &x) in a.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &y) in b.iter().enumerate() {
+                let cur = c[i + j] + x as u64 * y as u64 + carry;
+                c[i + j] = cur % base;
+                carry = cur / base;
+            }
+            c[i + b.len()] = carry;
+        }
+        c.into_iter().map(|x| x as u32).collect()
+    }
+    pub fn mul_karatsuba(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+        if b.len() < KARATSUBA_THRESHOLD {
+            return mul_schoolbook(a, b);
+        }
+        if b.len() * 2 <= a.len() {
+            let mut c = vec![];
+            for (i, a) in a.chunks(b.len()).enumerate() {
+                let mut d = mul_karatsuba(a, b);
+                trim(&mut d);
+                add_shifted(&mut c, &d, i * b.len());
+            }
+            return c;
+        }
+        let m = a.len() / 2;
+        let (a0, a1) = a.split_at(m);
+        let (b0, b1) = b.split_at(m);
+        let mut z0 = mul_karatsuba(a0, b0);
+        let mut z2 = mul_karatsuba(a1, b1);
+        let mut z1 = mul_karatsuba(&add(a0, a1), &add(b0, b1));
+        trim(&mut z0);
+        trim(&mut z2);
+        sub_assign(&mut z1, &z0);
+        sub_assign(&mut z1, &z2);
+        trim(&mut z1);
+        add_shifted(&mut z0, &z1, m);
+        add_shifted(&mut z0, &z2, m * 2);
+        z0
+    }
+    /// convolution in base $10^3$ over two NTT friendly primes, exact while
+    /// $10^6 \cdot 3\min(|a|,|b|) < 2013265921 \cdot 1811939329$
+    pub fn mul_ntt(a: &[u32], b: &[u32]) -> Vec<u32> {
+        type M1 = Modulo2013265921;
+        type M2 = Modulo1811939329;
+        fn split<M: MIntConvert<u32>>(a: &[u32]) -> Vec<MInt<M>> {
+            let mut res = Vec::with_capacity(a.len() * 3);
+            for &x in a {
+                res.push(MInt::from(x % 1000));
+                res.push(MInt::from(x / 1000 % 1000));
+                res.push(MInt::from(x / 1_000_000));
+            }
+            res
+        }
+        let c1 = Convolve::<M1>::convolve(split(a), split(b));
+        let c2 = Convolve::<M2>::convolve(split(a), split(b));
+        let p1 = MInt::<M1>::get_mod() as u64;
+        let t = MInt::<M2>::from(p1).inv();
+        let mut c = Vec::with_capacity(c1.len() / 3 + 2);
+        let (mut carry, mut limb, mut pw) = (0u64, 0u32, 1u32);
+        for (x1, x2) in c1.into_iter().zip(c2) {
+            let x1 = x1.inner();
+            let y = ((x2 - MInt::<M2>::from(x1)) * t).inner() as u64;
+            let cur = x1 as u64 + y * p1 + carry;
+            limb += (cur % 1000) as u32 * pw;
+            carry = cur / 1000;
+            pw *= 1000;
+            if pw == BASE {
+                c.push(limb);
+                limb = 0;
+                pw = 1;
+            }
+        }
+        while carry > 0 || pw > 1 {
+            limb += (carry % 1000) as u32 * pw;
+            carry /= 1000;
+            pw *= 1000;
+            if pw == BASE {
+                c.push(limb);
+                limb = 0;
+                pw = 1;
+            }
+        }
+        c
+    }
+    pub fn mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let mut c = if a.len().min(b.len()) >= NTT_THRESHOLD {
+            mul_ntt(a, b)
+        } else {
+            mul_karatsuba(a, b)
+        };
+        trim(&mut c);
+        c
+    }
+    /// `a` and `b` must be trimmed and `b` must not be zero
+    pub fn divrem(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+        if cmp(a, b) == Ordering::Less {
+            return (vec![], a.to_vec());
+        }
+        if b.len() == 1 {
+            let (q, r) = divrem_small(a, b[0]);
+            return (q, if r == 0 { vec![] } else { vec![r] });
+        }
+        // Knuth's algorithm D
+        let base = BASE as u64;
+        let norm = BASE / (b[b.len() - 1] + 1);
+        let mut u = mul_small(a, norm);
+        u.resize(a.len() + 1, 0);
+        let v = mul_small(b, norm);
+        let n = v.len();
+        let (vh, vl) = (v[n - 1] as u64, v[n - 2] as u64);
+        let mut q = vec![0u32; a.len() - n + 1];
+        for j in (0..q.len()).rev() {
+            let num = u[j + n] as u64 * base + u[j + n - 1] as u64;
+            let (mut qhat, mut rhat) = (num / vh, num % vh);
+            while qhat >= base || qhat * vl > rhat * base + u[j + n - 2] as u64 {
+                qhat -= 1;
+                rhat += vh;
+                if rhat >= base {
+                    break;
+                }
+            }
+            let (mut borrow, mut carry) = (0i64, 0u64);
+            for (i, &v) in v.iter().enumerate() {
+                let p = qhat * v as u64 + carry;
+                carry = p / base;
+                let t = u[i + j] as i64 - (p % base) as i64 + borrow;
+                borrow = if t < 0 { -1 } else { 0 };
+                u[i + j] = t.rem_euclid(base as i64) as u32;
+            }
+            let t = u[j + n] as i64 - carry as i64 + borrow;
+            u[j + n] = t.rem_euclid(base as i64) as u32;
+            if t < 0 {
+                qhat -= 1;
+                let mut carry = 0;
+                for (i, &v) in v.iter().enumerate() {
+                    let s = u[i + j] + v + carry;
+                    carry = (s >= BASE) as u32;
+                    u[i + j] = if carry == 1 { s - BASE } else { s };
+                }
+                u[j + n] = (u[j + n] + carry) % BASE;
+            }
+            q[j] = qhat as u32;
+        }
+        trim(&mut q);
+        u.truncate(n);
+        trim(&mut u);
+        (q, divrem_small(&u, norm).0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid digit found in string")
+    }
+}
+
+/// arbitrary precision unsigned integer
+///
+/// Multiplication is schoolbook, Karatsuba, or NTT by sizes, and division is $O(nm)$.
+/// This is not [`IntBase`](super::IntBase) since it is neither `Copy` nor `Bounded`, but the
+/// same operations are provided as inherent methods.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// little endian in base $10^9$ without leading zeros
+    limbs: Vec<u32>,
+}
+
+/// arbitrary precision signed integer, [`BigUint`] with a sign
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    /// `false` for zero
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigUint {
+    fn from_limbs(mut limbs: Vec<u32>) -> Self {
+        limbs::trim(&mut limbs);
+        Self { limbs }
+    }
+    /// (quotient, remainder)
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+        let (q, r) = limbs::divrem(&self.limbs, &rhs.limbs);
+        (Self { limbs: q }, Self { limbs: r })
+    }
+    pub fn div_euclid(&self, rhs: &Self) -> Self {
+        self / rhs
+    }
+    pub fn rem_euclid(&self, rhs: &Self) -> Self {
+        self % rhs
+    }
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut x = self.clone();
+        let mut y = Self::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                y *= &x;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                x = &x * &x;
+            }
+        }
+        y
+    }
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        assert!((2..=36).contains(&radix), "radix must be in [2, 36]");
+        if radix == 10 {
+            return src.parse();
+        }
+        let src = if src.starts_with('+') { &src[1..] } else { src };
+        if src.is_empty() {
+            return Err(ParseBigIntError);
+        }
+        let mut limbs = vec![];
+        for c in src.chars() {
+            let d = c.to_digit(radix).ok_or(ParseBigIntError)?;
+            limbs = limbs::mul_small(&limbs, radix);
+            limbs::add_shifted(&mut limbs, &[d], 0);
+        }
+        Ok(Self::from_limbs(limbs))
+    }
+    pub fn signed(self) -> BigInt {
+        BigInt::from(self)
+    }
+    pub fn abs_sub(&self, other: &Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let r = &a % &b;
+            a = b;
+            b = r;
+        }
+        a
+    }
+    pub fn lcm(&self, other: &Self) -> Self {
+        if self.is_zero() && other.is_zero() {
+            return Self::zero();
+        }
+        self / &self.gcd(other) * other
+    }
+    /// $(g, x, y)$ such that $ax + by = g = \gcd(a, b)$
+    pub fn extgcd(&self, other: &Self) -> (Self, BigInt, BigInt) {
+        let (mut a, mut b) = (self.clone().signed(), other.clone().signed());
+        let (mut u, mut v, mut x, mut y) =
+            (BigInt::one(), BigInt::zero(), BigInt::zero(), BigInt::one());
+        while !a.is_zero() {
+            let k = &b / &a;
+            x -= &k * &u;
+            y -= &k * &v;
+            b -= &k * &a;
+            std::mem::swap(&mut x, &mut u);
+            std::mem::swap(&mut y, &mut v);
+            std::mem::swap(&mut b, &mut a);
+        }
+        (b.magnitude, x, y)
+    }
+    pub fn modinv(&self, modulo: &Self) -> Self {
+        assert!(
+            !self.is_zero(),
+            "attempt to inverse zero with modulo {}",
+            modulo
+        );
+        let (g, x, _) = self.extgcd(modulo);
+        assert!(g.is_one(), "there is no inverse {} modulo {}", self, modulo);
+        x.rem_euclid(&modulo.clone().signed()).magnitude
+    }
+    pub fn to_u64(&self) -> Option<u64> {
+        let mut x = 0u64;
+        for &d in self.limbs.iter().rev() {
+            x = x.checked_mul(BASE as u64)?.checked_add(d as u64)?;
+        }
+        Some(x)
+    }
+    pub fn to_u128(&self) -> Option<u128> {
+        let mut x = 0u128;
+        for &d in self.limbs.iter().rev() {
+            x = x.checked_mul(BASE as u128)?.checked_add(d as u128)?;
+        }
+        Some(x)
+    }
+    fn add_impl(&self, rhs: &Self) -> Self {
+        Self::from_limbs(limbs::add(&self.limbs, &rhs.limbs))
+    }
+    fn sub_impl(&self, rhs: &Self) -> Self {
+        assert!(self >= rhs, "attempt to subtract with overflow");
+        let mut limbs = self.limbs.clone();
+        limbs::sub_assign(&mut limbs, &rhs.limbs);
+        Self::from_limbs(limbs)
+    }
+    fn mul_impl(&self, rhs: &Self) -> Self {
+        Self {
+            limbs: limbs::mul(&self.limbs, &rhs.limbs),
+        }
+    }
+    fn div_impl(&self, rhs: &Self) -> Self {
+        self.div_rem(rhs).0
+    }
+    fn rem_impl(&self, rhs: &Self) -> Self {
+        self.div_rem(rhs).1
+    }
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, magnitude: BigUint) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+    pub fn unsigned_abs(&self) -> BigUint {
+        self.magnitude.clone()
+    }
+    pub fn abs(&self) -> Self {
+        Self::from_parts(false, self.magnitude.clone())
+    }
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+    pub fn is_positive(&self) -> bool {
+        !self.negative && !self.magnitude.is_zero()
+    }
+    pub fn signum(&self) -> Self {
+        Self::from_parts(
+            self.negative,
+            if self.magnitude.is_zero() {
+                BigUint::zero()
+            } else {
+                BigUint::one()
+            },
+        )
+    }
+    /// (quotient, remainder) rounded toward zero
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (q, r) = self.magnitude.div_rem(&rhs.magnitude);
+        (
+            Self::from_parts(self.negative ^ rhs.negative, q),
+            Self::from_parts(self.negative, r),
+        )
+    }
+    pub fn div_euclid(&self, rhs: &Self) -> Self {
+        let (q, r) = self.div_rem(rhs);
+        if r.negative {
+            if rhs.negative {
+                q + Self::one()
+            } else {
+                q - Self::one()
+            }
+        } else {
+            q
+        }
+    }
+    pub fn rem_euclid(&self, rhs: &Self) -> Self {
+        let r = self % rhs;
+        if r.negative {
+            r + rhs.abs()
+        } else {
+            r
+        }
+    }
+    pub fn pow(&self, exp: u32) -> Self {
+        Self::from_parts(self.negative && exp & 1 == 1, self.magnitude.pow(exp))
+    }
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        if !src.starts_with('-') {
+            Ok(Self::from_parts(
+                false,
+                BigUint::from_str_radix(src, radix)?,
+            ))
+        } else if src[1..].starts_with('+') {
+            Err(ParseBigIntError)
+        } else {
+            Ok(Self::from_parts(
+                true,
+                BigUint::from_str_radix(&src[1..], radix)?,
+            ))
+        }
+    }
+    pub fn to_i64(&self) -> Option<i64> {
+        let x = self.magnitude.to_u64()?;
+        if self.negative {
+            if x <= std::i64::MIN as u64 {
+                Some((x as i64).wrapping_neg())
+            } else {
+                None
+            }
+        } else {
+            i64::try_from(x).ok()
+        }
+    }
+    pub fn to_i128(&self) -> Option<i128> {
+        let x = self.magnitude.to_u128()?;
+        if self.negative {
+            if x <= std::i128::MIN as u128 {
+                Some((x as i128).wrapping_neg())
+            } else {
+                None
+            }
+        } else {
+            i128::try_from(x).ok()
+        }
+    }
+    fn add_signed(&self, negative: bool, magnitude: &BigUint) -> Self {
+        if self.negative == negative {
+            Self::from_parts(negative, &self.magnitude + magnitude)
+        } else if self.magnitude >= *magnitude {
+            Self::from_parts(self.negative, &self.magnitude - magnitude)
+        } else {
+            Self::from_parts(negative, magnitude - &self.magnitude)
+        }
+    }
+    fn add_impl(&self, rhs: &Self) -> Self {
+        self.add_signed(rhs.negative, &rhs.magnitude)
+    }
+    fn sub_impl(&self, rhs: &Self) -> Self {
+        self.add_signed(!rhs.negative, &rhs.magnitude)
+    }
+    fn mul_impl(&self, rhs: &Self) -> Self {
+        Self::from_parts(
+            self.negative ^ rhs.negative,
+            &self.magnitude * &rhs.magnitude,
+        )
+    }
+    fn div_impl(&self, rhs: &Self) -> Self {
+        self.div_rem(rhs).0
+    }
+    fn rem_impl(&self, rhs: &Self) -> Self {
+        self.div_rem(rhs).1
+    }
+}
+
+macro_rules! impl_bigint_binop {
+    ($t:ty, $($Trait:ident $method:ident $TraitAssign:ident $method_assign:ident $f:ident)*) => {$(
+        impl<'a, 'b> $Trait<&'b $t> for &'a $t {
+            type Output = $t;
+            fn $method(self, rhs: &'b $t) -> $t {
+                <$t>::$f(self, rhs)
+            }
+        }
+        impl<'a> $Trait<&'a $t> for $t {
+            type Output = $t;
+            fn $method(self, rhs: &'a $t) -> $t {
+                <$t>::$f(&self, rhs)
+            }
+        }
+        impl<'a> $Trait<$t> for &'a $t {
+            type Output = $t;
+            fn $method(self, rhs: $t) -> $t {
+                <$t>::$f(self, &rhs)
+            }
+        }
+        impl $Trait<$t> for $t {
+            type Output = $t;
+            fn $method(self, rhs: $t) -> $t {
+                <$t>::$f(&self, &rhs)
+            }
+        }
+        impl<'a> $TraitAssign<&'a $t> for $t {
+            fn $method_assign(&mut self, rhs: &'a $t) {
+                *self = <$t>::$f(self, rhs);
+            }
+        }
+        impl $TraitAssign<$t> for $t {
+            fn $method_assign(&mut self, rhs: $t) {
+                *self = <$t>::$f(self, &rhs);
+            }
+        }
+    )*};
+}
+impl_bigint_binop!(
+    BigUint,
+    Add add AddAssign add_assign add_impl
+    Sub sub SubAssign sub_assign sub_impl
+    Mul mul MulAssign mul_assign mul_impl
+    Div div DivAssign div_assign div_impl
+    Rem rem RemAssign rem_assign rem_impl
+);
+impl_bigint_binop!(
+    BigInt,
+    Add add AddAssign add_assign add_impl
+    Sub sub SubAssign sub_assign sub_impl
+    Mul mul MulAssign mul_assign mul_impl
+    Div div DivAssign div_assign div_impl
+    Rem rem RemAssign rem_assign rem_impl
+);
+
+impl Neg for BigInt {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::from_parts(!self.negative, self.magnitude)
+    }
+}
+impl Neg for &BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        limbs::cmp(&self.limbs, &other.limbs)
+    }
+}
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Zero for BigUint {
+    fn zero() -> Self {
+        Self { limbs: vec![] }
+    }
+}
+impl One for BigUint {
+    fn one() -> Self {
+        Self { limbs: vec![1] }
+    }
+}
+impl Zero for BigInt {
+    fn zero() -> Self {
+        Self::from(BigUint::zero())
+    }
+}
+impl One for BigInt {
+    fn one() -> Self {
+        Self::from(BigUint::one())
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(magnitude: BigUint) -> Self {
+        Self {
+            negative: false,
+            magnitude,
+        }
+    }
+}
+macro_rules! impl_from_primitive {
+    ($($u:ident $i:ident)*) => {$(
+        impl From<$u> for BigUint {
+            fn from(x: $u) -> Self {
+                let mut x = x as u128;
+                let mut limbs = vec![];
+                while x > 0 {
+                    limbs.push((x % BASE as u128) as u32);
+                    x /= BASE as u128;
+                }
+                Self { limbs }
+            }
+        }
+        impl From<$u> for BigInt {
+            fn from(x: $u) -> Self {
+                Self::from(BigUint::from(x))
+            }
+        }
+        impl From<$i> for BigInt {
+            fn from(x: $i) -> Self {
+                let abs = if x < 0 { (x as $u).wrapping_neg() } else { x as $u };
+                Self::from_parts(x < 0, BigUint::from(abs))
+            }
+        }
+    )*};
+}
+impl_from_primitive!(u8 i8 u16 i16 u32 i32 u64 i64 u128 i128 usize isize);
+
+impl FromStr for BigUint {
+    type Err = ParseBigIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = if s.starts_with('+') { &s[1..] } else { s }.as_bytes();
+        if s.is_empty() || !s.iter().all(u8::is_ascii_digit) {
+            return Err(ParseBigIntError);
+        }
+        let limbs = s
+            .rchunks(BASE_DIGITS)
+            .map(|c| c.iter().fold(0, |x, &d| x * 10 + (d - b'0') as u32))
+            .collect();
+        Ok(Self::from_limbs(limbs))
+    }
+}
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+impl BigUint {
+    fn to_decimal(&self) -> String {
+        let mut s = String::with_capacity(self.limbs.len() * BASE_DIGITS);
+        match self.limbs.split_last() {
+            Some((last, rest)) => {
+                s.push_str(&last.to_string());
+                for d in rest.iter().rev() {
+                    s.push_str(&format!("{:09}", d));
+                }
+            }
+            None => s.push('0'),
+        }
+        s
+    }
+}
+impl Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "", &self.to_decimal())
+    }
+}
+impl Debug for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+impl Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(!self.negative, "", &self.magnitude.to_decimal())
+    }
+}
+impl Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl IterScan for BigUint {
+    type Output = Self;
+    fn scan<'a, I: Iterator<Item = &'a str>>(iter: &mut I) -> Option<Self::Output> {
+        iter.next()?.parse().ok()
+    }
+}
+impl IterScan for BigInt {
+    type Output = Self;
+    fn scan<'a, I: Iterator<Item = &'a str>>(iter: &mut I) -> Option<Self::Output> {
+        iter.next()?.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rand, tools::Xorshift};
+
+    fn random_digits(rng: &mut Xorshift, len: usize) -> String {
+        (0..len)
+            .map(|i| (b'0' + rng.gen(if i == 0 { 1..10 } else { 0..10 }) as u8) as char)
+            .collect()
+    }
+
+    #[test]
+    fn test_bigint_small() {
+        let mut rng = Xorshift::default();
+        for _ in 0..10000 {
+            let a = (rng.rand64() as i64 >> rng.gen(0..64u32)) as i128;
+            let b = (rng.rand64() as i64 >> rng.gen(0..64u32)) as i128;
+            let (x, y) = (BigInt::from(a), BigInt::from(b));
+            assert_eq!(x.to_string(), a.to_string());
+            assert_eq!(a.to_string().parse::<BigInt>(), Ok(x.clone()));
+            assert_eq!(x.to_i128(), Some(a));
+            assert_eq!((&x + &y).to_i128(), Some(a + b));
+            assert_eq!((&x - &y).to_i128(), Some(a - b));
+            assert_eq!((&x * &y).to_i128(), Some(a * b));
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+            assert_eq!(x.abs().to_i128(), Some(a.abs()));
+            assert_eq!(x.signum().to_i128(), Some(a.signum()));
+            if b != 0 {
+                assert_eq!((&x / &y).to_i128(), Some(a / b));
+                assert_eq!((&x % &y).to_i128(), Some(a % b));
+                assert_eq!(x.div_euclid(&y).to_i128(), Some(a.div_euclid(b)));
+                assert_eq!(x.rem_euclid(&y).to_i128(), Some(a.rem_euclid(b)));
+            }
+            let (a, b) = (a.unsigned_abs(), b.unsigned_abs() >> 32);
+            let (x, y) = (BigUint::from(a), BigUint::from(b));
+            assert_eq!((&x * &x).to_u128(), Some(a * a));
+            assert_eq!(x.gcd(&y).to_u128(), Some(gcd(a, b)));
+            let (g, s, t) = x.extgcd(&y);
+            assert_eq!(g.to_u128(), Some(gcd(a, b)));
+            assert_eq!(
+                &x.clone().signed() * &s + &y.clone().signed() * &t,
+                g.signed()
+            );
+            if b > 1 && gcd(a, b) == 1 {
+                assert_eq!((&x * &x.modinv(&y) % &y).to_u128(), Some(1));
+            }
+            assert_eq!(x.pow(2).to_u128(), Some(a * a));
+            assert_eq!(
+                BigUint::from_str_radix(&format!("{:x}", a), 16).unwrap(),
+                x.clone()
+            );
+            if let Some(q) = a.checked_div(b) {
+                assert_eq!((&x / &y).to_u128(), Some(q));
+                assert_eq!((&x % &y).to_u128(), Some(a - q * b));
+            }
+        }
+        assert_eq!(BigInt::from(-5).pow(3), BigInt::from(-125));
+        for &x in [std::i64::MIN, std::i64::MIN + 1, std::i64::MAX].iter() {
+            assert_eq!(BigInt::from(x).to_i64(), Some(x));
+        }
+        assert_eq!((BigInt::from(std::i64::MIN) - BigInt::one()).to_i64(), None);
+        assert_eq!(BigInt::from(std::i128::MIN).to_i128(), Some(std::i128::MIN));
+        assert_eq!(format!("{:>5}", BigInt::from(-12)), "  -12");
+        assert_eq!("-0".parse::<BigInt>(), Ok(BigInt::zero()));
+        assert_eq!("000123".parse::<BigUint>(), Ok(BigUint::from(123u32)));
+        assert!("".parse::<BigInt>().is_err());
+        assert!("-".parse::<BigInt>().is_err());
+        assert!("-+1".parse::<BigInt>().is_err());
+        assert!("12a".parse::<BigUint>().is_err());
+    }
+
+    fn gcd(a: u128, b: u128) -> u128 {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    #[test]
+    fn test_bigint_large() {
+        let mut rng = Xorshift::default();
+        for _ in 0..200 {
+            rand!(rng, n: (1..=1000), m: (1..=1000));
+            let a: BigUint = random_digits(&mut rng, n).parse().unwrap();
+            let b: BigUint = random_digits(&mut rng, m).parse().unwrap();
+            let c = &a * &b;
+            let mut d = limbs::mul_schoolbook(&a.limbs, &b.limbs);
+            limbs::trim(&mut d);
+            assert_eq!(c.limbs, d);
+            let (q, r) = c.div_rem(&b);
+            assert_eq!(q, a);
+            assert!(r.is_zero());
+            let d = &c + &b - BigUint::one();
+            let (q, r) = d.div_rem(&b);
+            assert_eq!(q, a);
+            assert_eq!(r, &b - BigUint::one());
+            let (q, r) = a.div_rem(&b);
+            assert!(r < b);
+            assert_eq!(q * &b + r, a);
+            assert_eq!(a.to_string().parse::<BigUint>(), Ok(a.clone()));
+        }
+    }
+
+    #[test]
+    fn test_bigint_ntt() {
+        let mut rng = Xorshift::default();
+        for _ in 0..5 {
+            rand!(rng, n: (3000..=10000), m: (3000..=10000));
+            let a = random_digits(&mut rng, n).parse::<BigUint>().unwrap();
+            let b = random_digits(&mut rng, m).parse::<BigUint>().unwrap();
+            let mut c = limbs::mul_ntt(&a.limbs, &b.limbs);
+            limbs::trim(&mut c);
+            let mut d = limbs::mul_karatsuba(&a.limbs, &b.limbs);
+            limbs::trim(&mut d);
+            assert_eq!(c, d);
+        }
+        let a = BigUint::from(BASE - 1).pow(3000);
+        let mut d = limbs::mul_karatsuba(&a.limbs, &a.limbs);
+        limbs::trim(&mut d);
+        assert_eq!((&a * &a).limbs, d);
+        assert_eq!((&a * &a).div_rem(&a), (a.clone(), BigUint::zero()));
+    }
+}
@@ -2,6 +2,8 @@ use crate::tools::IterScan;
 
 #[codesnip::entry("BarrettReduction")]
 pub use self::barrett_reduction::BarrettReduction;
+#[codesnip::entry("BigInt")]
+pub use self::bigint::{BigInt, BigUint, ParseBigIntError};
 #[codesnip::entry("bounded")]
 pub use self::bounded::Bounded;
 #[codesnip::entry("Complex")]
@@ -24,6 +26,14 @@ pub use self::zero_one::{One, Zero};
 
 #[cfg_attr(nightly, codesnip::entry("BarrettReduction"))]
 mod barrett_reduction;
+#[cfg_attr(
+    nightly,
+    codesnip::entry(
+        "BigInt",
+        include("zero_one", "scanner", "montgomery", "NumberTheoreticTransform")
+    )
+)]
+mod bigint;
 #[cfg_attr(nightly, codesnip::entry)]
 mod bounded;
 #[cfg_attr(nightly, codesnip::entry("Complex", include("zero_one", "scanner")))]
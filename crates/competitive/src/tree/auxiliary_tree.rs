@@ -0,0 +1,133 @@
+use super::{HeavyLightDecomposition, LcaMonoidDispatch, LowestCommonAncestor};
+use crate::graph::UndirectedSparseGraph;
+
+#[codesnip::entry("AuxiliaryTree")]
+/// LCA on a rooted tree with dfs preorder
+pub trait LcaProvider {
+    /// index in dfs preorder
+    fn preorder(&self, u: usize) -> usize;
+    fn lca(&self, u: usize, v: usize) -> usize;
+}
+#[codesnip::entry("AuxiliaryTree", include("HeavyLightDecomposition"))]
+impl LcaProvider for HeavyLightDecomposition {
+    fn preorder(&self, u: usize) -> usize {
+        self.vidx[u]
+    }
+    fn lca(&self, u: usize, v: usize) -> usize {
+        HeavyLightDecomposition::lca(self, u, v)
+    }
+}
+#[codesnip::entry("AuxiliaryTree", include("LowestCommonAncestor"))]
+impl<D> LcaProvider for LowestCommonAncestor<'_, D>
+where
+    D: LcaMonoidDispatch,
+{
+    fn preorder(&self, u: usize) -> usize {
+        self.euler().vidx[u].0
+    }
+    fn lca(&self, u: usize, v: usize) -> usize {
+        LowestCommonAncestor::lca(self, u, v)
+    }
+}
+
+#[codesnip::entry("AuxiliaryTree", include("SparseGraph"))]
+/// auxiliary tree (virtual tree) of vertex subsets of a rooted tree
+#[derive(Debug, Clone)]
+pub struct AuxiliaryTreeBuilder<'a, L> {
+    lca: &'a L,
+    depth: &'a [u64],
+}
+#[codesnip::entry("AuxiliaryTree")]
+impl<'a, L> AuxiliaryTreeBuilder<'a, L>
+where
+    L: LcaProvider,
+{
+    /// `depth` of the same rooted tree as `lca`, e.g. `graph.tree_depth(root)`
+    pub fn new(lca: &'a L, depth: &'a [u64]) -> Self {
+        Self { lca, depth }
+    }
+    /// (tree, edge lengths, original ids) in O(k log k)
+    ///
+    /// The tree consists of `vs` and their pairwise LCAs rooted at `0`, and vertices are
+    /// sorted by dfs preorder.
+    pub fn build(&self, vs: &[usize]) -> (UndirectedSparseGraph, Vec<u64>, Vec<usize>) {
+        let mut ids = vs.to_vec();
+        ids.sort_unstable_by_key(|&u| self.lca.preorder(u));
+        ids.dedup();
+        for i in 1..ids.len() {
+            ids.push(self.lca.lca(ids[i - 1], ids[i]));
+        }
+        ids.sort_unstable_by_key(|&u| self.lca.preorder(u));
+        ids.dedup();
+        let mut edges = Vec::with_capacity(ids.len().saturating_sub(1));
+        let mut lengths = Vec::with_capacity(ids.len().saturating_sub(1));
+        for i in 1..ids.len() {
+            let p = self.lca.lca(ids[i - 1], ids[i]);
+            let pi = ids[..i]
+                .binary_search_by_key(&self.lca.preorder(p), |&u| self.lca.preorder(u))
+                .unwrap();
+            edges.push((pi, i));
+            lengths.push(self.depth[ids[i]] - self.depth[p]);
+        }
+        (
+            UndirectedSparseGraph::from_edges(ids.len(), edges),
+            lengths,
+            ids,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        rand,
+        tools::Xorshift,
+        tree::{EulerTourForRichVertex, LcaMonoidDefaultId, LevelAncestor, MixedTree},
+    };
+
+    #[test]
+    fn test_auxiliary_tree() {
+        const Q: usize = 100;
+        const N: usize = 60;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), root: (0..n), k: (0..=n));
+            let g = rng.gen(MixedTree(n));
+            let depth = g.tree_depth(root);
+            let la = LevelAncestor::from_tree(&g, root);
+            let vs: Vec<_> = (0..k).map(|_| rng.gen(0..n)).collect();
+            let check = |(tree, lengths, ids): (UndirectedSparseGraph, Vec<u64>, Vec<usize>)| {
+                let m = ids.len();
+                assert!(m <= (vs.len() * 2).saturating_sub(1));
+                assert!(vs.iter().all(|v| ids.contains(v)));
+                for i in 0..m {
+                    for j in 0..m {
+                        assert!(ids.contains(&la.lca(ids[i], ids[j])));
+                    }
+                }
+                if m == 0 {
+                    return;
+                }
+                assert_eq!(tree.edges_size(), m - 1);
+                let tdepth = tree
+                    .weighted_tree_depth::<crate::algebra::AdditiveOperation<u64>, _>(0, |eid| {
+                        lengths[eid]
+                    });
+                for i in 0..m {
+                    assert_eq!(tdepth[i] + depth[ids[0]], depth[ids[i]]);
+                    for a in tree.adjacencies(i) {
+                        if a.to < i {
+                            assert_eq!(la.la(ids[i], lengths[a.id] as usize), Some(ids[a.to]));
+                        }
+                    }
+                }
+            };
+            let euler = EulerTourForRichVertex::new(root, &g);
+            let lca = euler.gen_lca::<LcaMonoidDefaultId>();
+            check(AuxiliaryTreeBuilder::new(&lca, &depth).build(&vs));
+            let hld = HeavyLightDecomposition::new(root, &g);
+            check(AuxiliaryTreeBuilder::new(&hld, &depth).build(&vs));
+        }
+    }
+}
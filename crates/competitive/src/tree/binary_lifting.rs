@@ -0,0 +1,263 @@
+use crate::{algebra::SemiGroup, graph::UndirectedSparseGraph};
+
+#[codesnip::entry("BinaryLifting", include("algebra", "tree_order"))]
+/// binary lifting on a rooted forest with path folds of a [`SemiGroup`] on vertices
+#[derive(Debug, Clone)]
+pub struct BinaryLifting<S>
+where
+    S: SemiGroup,
+{
+    depth: Vec<usize>,
+    jump: Vec<Vec<usize>>,
+    /// fold of $2^j$ vertices from `v` toward the root in upward order
+    up: Vec<Vec<S::T>>,
+    /// the same vertices as `up` in downward order
+    down: Vec<Vec<S::T>>,
+}
+#[codesnip::entry("BinaryLifting")]
+impl<S> BinaryLifting<S>
+where
+    S: SemiGroup,
+{
+    /// forest given by parents, `!0` for roots
+    pub fn new(parent: &[usize], value: Vec<S::T>) -> Self {
+        let n = parent.len();
+        assert_eq!(n, value.len());
+        let mut children = vec![vec![]; n];
+        let mut order = Vec::with_capacity(n);
+        for (v, &p) in parent.iter().enumerate() {
+            if p == !0 {
+                order.push(v);
+            } else {
+                children[p].push(v);
+            }
+        }
+        let mut depth = vec![0usize; n];
+        let mut i = 0;
+        while i < order.len() {
+            let u = order[i];
+            i += 1;
+            for &v in children[u].iter() {
+                depth[v] = depth[u] + 1;
+                order.push(v);
+            }
+        }
+        assert_eq!(order.len(), n, "parent must form a forest");
+        let mut jump = vec![parent.to_vec()];
+        let mut up = vec![value.clone()];
+        let mut down = vec![value];
+        // fold of the whole path needs $2^j \le n$
+        while 1 << jump.len() <= n {
+            let (pj, pu, pd) = (
+                jump.last().unwrap(),
+                up.last().unwrap(),
+                down.last().unwrap(),
+            );
+            let mut nj = Vec::with_capacity(n);
+            let mut nu = Vec::with_capacity(n);
+            let mut nd = Vec::with_capacity(n);
+            for v in 0..n {
+                let w = pj[v];
+                if w == !0 {
+                    nj.push(!0);
+                    nu.push(pu[v].clone());
+                    nd.push(pd[v].clone());
+                } else {
+                    nj.push(pj[w]);
+                    nu.push(S::operate(&pu[v], &pu[w]));
+                    nd.push(S::operate(&pd[w], &pd[v]));
+                }
+            }
+            jump.push(nj);
+            up.push(nu);
+            down.push(nd);
+        }
+        Self {
+            depth,
+            jump,
+            up,
+            down,
+        }
+    }
+    /// rooted tree
+    pub fn from_tree(graph: &UndirectedSparseGraph, root: usize, value: Vec<S::T>) -> Self {
+        Self::new(&graph.tree_order(root).1, value)
+    }
+    pub fn depth(&self, u: usize) -> usize {
+        self.depth[u]
+    }
+    /// `k`-th ancestor of `u`
+    pub fn kth_ancestor(&self, mut u: usize, k: usize) -> Option<usize> {
+        if k > self.depth[u] {
+            return None;
+        }
+        for (j, jump) in self.jump.iter().enumerate() {
+            if k >> j & 1 == 1 {
+                u = jump[u];
+            }
+        }
+        Some(u)
+    }
+    /// `u` and `v` must be in the same tree
+    pub fn lca(&self, u: usize, v: usize) -> usize {
+        let (mut u, mut v) = if self.depth[u] >= self.depth[v] {
+            (
+                self.kth_ancestor(u, self.depth[u] - self.depth[v]).unwrap(),
+                v,
+            )
+        } else {
+            (
+                u,
+                self.kth_ancestor(v, self.depth[v] - self.depth[u]).unwrap(),
+            )
+        };
+        if u == v {
+            return u;
+        }
+        for jump in self.jump.iter().rev() {
+            if jump[u] != jump[v] {
+                u = jump[u];
+                v = jump[v];
+            }
+        }
+        self.jump[0][u]
+    }
+    /// fold of `k` vertices from `u` toward the root, in downward order if `rev`
+    fn fold_up(&self, mut u: usize, k: usize, rev: bool) -> Option<S::T> {
+        let mut acc: Option<S::T> = None;
+        for j in 0..self.jump.len() {
+            if k >> j & 1 == 1 {
+                acc = Some(match (acc, rev) {
+                    (None, false) => self.up[j][u].clone(),
+                    (None, true) => self.down[j][u].clone(),
+                    (Some(x), false) => S::operate(&x, &self.up[j][u]),
+                    (Some(x), true) => S::operate(&self.down[j][u], &x),
+                });
+                u = self.jump[j][u];
+            }
+        }
+        acc
+    }
+    /// fold of vertices on the path from `u` to `v` in this order
+    pub fn fold_path(&self, u: usize, v: usize) -> S::T {
+        let l = self.lca(u, v);
+        let x = self
+            .fold_up(u, self.depth[u] - self.depth[l] + 1, false)
+            .unwrap();
+        match self.fold_up(v, self.depth[v] - self.depth[l], true) {
+            Some(y) => S::operate(&x, &y),
+            None => x,
+        }
+    }
+    /// fold of edges on the path from `u` to `v` in this order, where the value of an edge is
+    /// placed on its child vertex
+    pub fn fold_path_edges(&self, u: usize, v: usize) -> Option<S::T> {
+        let l = self.lca(u, v);
+        let x = self.fold_up(u, self.depth[u] - self.depth[l], false);
+        let y = self.fold_up(v, self.depth[v] - self.depth[l], true);
+        match (x, y) {
+            (Some(x), Some(y)) => Some(S::operate(&x, &y)),
+            (x, None) => x,
+            (None, y) => y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algebra::{LinearOperation, Magma},
+        num::mint_basic::MInt998244353,
+        rand,
+        tools::Xorshift,
+        tree::{LevelAncestor, MixedTree},
+    };
+
+    #[test]
+    fn test_binary_lifting() {
+        const Q: usize = 100;
+        const N: usize = 60;
+        type M = LinearOperation<MInt998244353>;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), root: (0..n));
+            let g = rng.gen(MixedTree(n));
+            let value: Vec<(MInt998244353, MInt998244353)> = (0..n)
+                .map(|_| (rng.gen(1..100u32).into(), rng.gen(0..100u32).into()))
+                .collect();
+            let bl = BinaryLifting::<M>::from_tree(&g, root, value.clone());
+            let la = LevelAncestor::from_tree(&g, root);
+            let (_, parent) = g.tree_order(root);
+            let path_to_root = |mut u: usize| {
+                let mut path = vec![u];
+                while parent[u] != !0 {
+                    u = parent[u];
+                    path.push(u);
+                }
+                path
+            };
+            for (u, &p) in parent.iter().enumerate() {
+                assert_eq!(la.depth(u), bl.depth(u));
+                assert_eq!(la.parent(u), if u == root { None } else { Some(p) });
+                for k in 0..=n {
+                    assert_eq!(la.la(u, k), bl.kth_ancestor(u, k));
+                }
+                for v in 0..n {
+                    let mut pu = path_to_root(u);
+                    let mut pv = path_to_root(v);
+                    let mut l = u;
+                    while pu.last().is_some() && pu.last() == pv.last() {
+                        l = pu.pop().unwrap();
+                        pv.pop();
+                    }
+                    assert_eq!(la.lca(u, v), l);
+                    assert_eq!(bl.lca(u, v), l);
+                    let mut path = pu.clone();
+                    path.push(l);
+                    path.extend(pv.iter().rev());
+                    assert_eq!(la.dist(u, v), path.len() - 1);
+                    for k in 0..=n {
+                        assert_eq!(la.jump(u, v, k), path.get(k).cloned());
+                    }
+                    let expected = path[1..]
+                        .iter()
+                        .fold(value[path[0]], |acc, &w| M::operate(&acc, &value[w]));
+                    assert_eq!(bl.fold_path(u, v), expected);
+                    let edges: Vec<_> = pu.iter().chain(pv.iter().rev()).cloned().collect();
+                    let expected = edges
+                        .iter()
+                        .map(|&w| value[w])
+                        .reduce(|acc, x| M::operate(&acc, &x));
+                    assert_eq!(bl.fold_path_edges(u, v), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binary_lifting_whole_path() {
+        type M = LinearOperation<MInt998244353>;
+        let mut rng = Xorshift::default();
+        for n in [2usize, 4, 8, 16, 32].iter().cloned() {
+            // path 0 - 1 - ... - (n-1)
+            let parent: Vec<_> = (0..n).map(|v| v.wrapping_sub(1)).collect();
+            let value: Vec<(MInt998244353, MInt998244353)> = (0..n)
+                .map(|_| (rng.gen(1..100u32).into(), rng.gen(0..100u32).into()))
+                .collect();
+            let bl = BinaryLifting::<M>::new(&parent, value.clone());
+            let expected = (1..n).fold(value[n - 1], |acc, w| M::operate(&acc, &value[n - 1 - w]));
+            assert_eq!(bl.fold_path(n - 1, 0), expected);
+            let expected = (1..n).fold(value[0], |acc, w| M::operate(&acc, &value[w]));
+            assert_eq!(bl.fold_path(0, n - 1), expected);
+        }
+        // 2-vertex tree
+        let g = UndirectedSparseGraph::from_edges(2, vec![(0, 1)]);
+        let value: Vec<(MInt998244353, MInt998244353)> =
+            vec![(1.into(), 2.into()), (3.into(), 4.into())];
+        let bl = BinaryLifting::<M>::from_tree(&g, 0, value.clone());
+        assert_eq!(bl.fold_path(1, 0), M::operate(&value[1], &value[0]));
+        assert_eq!(bl.fold_path(0, 1), M::operate(&value[0], &value[1]));
+        assert_eq!(bl.fold_path_edges(1, 0), Some(value[1]));
+    }
+}
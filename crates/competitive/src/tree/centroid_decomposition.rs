@@ -0,0 +1,354 @@
+use crate::graph::UndirectedSparseGraph;
+use std::collections::BTreeSet;
+
+/// centroid decomposition of a forest
+#[derive(Debug, Clone)]
+pub struct CentroidDecomposition<'a> {
+    graph: &'a UndirectedSparseGraph,
+    /// centroids in decomposition order
+    order: Vec<usize>,
+    /// parent in the centroid tree, `!0` for roots
+    parent: Vec<usize>,
+    level: Vec<usize>,
+    /// dist[v][i]: distance from `v` to its ancestor at level `i` in the centroid tree
+    dist: Vec<Vec<usize>>,
+}
+impl<'a> CentroidDecomposition<'a> {
+    pub fn new(graph: &'a UndirectedSparseGraph) -> Self {
+        let n = graph.vertices_size();
+        let mut order = Vec::with_capacity(n);
+        let mut parent = vec![!0usize; n];
+        let mut level = vec![!0usize; n];
+        let mut dist = vec![vec![]; n];
+        let mut size = vec![0usize; n];
+        let mut bfs = Vec::with_capacity(n);
+        let mut stack = vec![];
+        for r in graph.vertices() {
+            if level[r] != !0 {
+                continue;
+            }
+            stack.push((r, !0, 0));
+            while let Some((s, p, lv)) = stack.pop() {
+                // (vertex, parent) in bfs order of the current component
+                bfs.clear();
+                bfs.push((s, !0));
+                let mut i = 0;
+                while i < bfs.len() {
+                    let (u, pu) = bfs[i];
+                    i += 1;
+                    for a in graph.adjacencies(u) {
+                        if a.to != pu && level[a.to] == !0 {
+                            bfs.push((a.to, u));
+                        }
+                    }
+                }
+                for &(u, _) in bfs.iter() {
+                    size[u] = 1;
+                }
+                for &(u, pu) in bfs[1..].iter().rev() {
+                    size[pu] += size[u];
+                }
+                // the deepest vertex whose subtree has at least half of the vertices
+                let total = bfs.len();
+                let c = bfs
+                    .iter()
+                    .rev()
+                    .map(|&(u, _)| u)
+                    .find(|&u| size[u] * 2 >= total)
+                    .unwrap();
+                order.push(c);
+                parent[c] = p;
+                level[c] = lv;
+                bfs.clear();
+                bfs.push((c, !0));
+                dist[c].push(0);
+                let mut i = 0;
+                while i < bfs.len() {
+                    let (u, pu) = bfs[i];
+                    i += 1;
+                    for a in graph.adjacencies(u) {
+                        if a.to != pu && level[a.to] == !0 {
+                            let d = *dist[u].last().unwrap() + 1;
+                            dist[a.to].push(d);
+                            bfs.push((a.to, u));
+                        }
+                    }
+                }
+                for a in graph.adjacencies(c) {
+                    if level[a.to] == !0 {
+                        stack.push((a.to, c, lv + 1));
+                    }
+                }
+            }
+        }
+        Self {
+            graph,
+            order,
+            parent,
+            level,
+            dist,
+        }
+    }
+    pub fn graph(&self) -> &'a UndirectedSparseGraph {
+        self.graph
+    }
+    /// centroids in decomposition order
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+    /// parent in the centroid tree
+    pub fn parent(&self, u: usize) -> Option<usize> {
+        if self.parent[u] == !0 {
+            None
+        } else {
+            Some(self.parent[u])
+        }
+    }
+    /// depth in the centroid tree, at most log2(n)
+    pub fn level(&self, u: usize) -> usize {
+        self.level[u]
+    }
+    /// centroid tree as a forest
+    pub fn centroid_tree(&self) -> UndirectedSparseGraph {
+        let edges = self
+            .graph
+            .vertices()
+            .filter(|&u| self.parent[u] != !0)
+            .map(|u| (self.parent[u], u))
+            .collect();
+        UndirectedSparseGraph::from_edges(self.graph.vertices_size(), edges)
+    }
+    /// distance from `u` to its ancestor at `level` in the centroid tree
+    pub fn dist(&self, u: usize, level: usize) -> usize {
+        self.dist[u][level]
+    }
+    /// (ancestor, distance) in the centroid tree from `u` to the root
+    pub fn ancestors(&self, u: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let mut c = u;
+        self.dist[u].iter().rev().map(move |&d| {
+            let res = (c, d);
+            c = self.parent[c];
+            res
+        })
+    }
+    /// Call `f(c, subtrees)` for each centroid `c` in decomposition order, where `subtrees`
+    /// are the components adjacent to `c` after removing `c` from its component.
+    ///
+    /// Each subtree is the list of `(vertex, parent, edge id)` in bfs order from `c`, and the
+    /// distance from `c` to `vertex` is `dist(vertex, level(c))`.
+    pub fn for_each_centroid<F>(&self, mut f: F)
+    where
+        F: FnMut(usize, &[Vec<(usize, usize, usize)>]),
+    {
+        let mut removed = vec![false; self.graph.vertices_size()];
+        let mut subtrees: Vec<Vec<(usize, usize, usize)>> = vec![];
+        for &c in self.order.iter() {
+            removed[c] = true;
+            subtrees.clear();
+            for a in self.graph.adjacencies(c) {
+                if removed[a.to] {
+                    continue;
+                }
+                let mut sub = vec![(a.to, c, a.id)];
+                let mut i = 0;
+                while i < sub.len() {
+                    let (u, pu, _) = sub[i];
+                    i += 1;
+                    for a in self.graph.adjacencies(u) {
+                        if a.to != pu && !removed[a.to] {
+                            sub.push((a.to, u, a.id));
+                        }
+                    }
+                }
+                subtrees.push(sub);
+            }
+            f(c, &subtrees);
+        }
+    }
+}
+
+/// nearest marked vertex queries on a tree in O(log^2 n)
+#[derive(Debug, Clone)]
+pub struct NearestMarkedVertex<'a, 'b> {
+    cd: &'b CentroidDecomposition<'a>,
+    /// (distance, vertex) of marked vertices in the component of each centroid
+    marked: Vec<BTreeSet<(usize, usize)>>,
+}
+impl<'a, 'b> NearestMarkedVertex<'a, 'b> {
+    pub fn new(cd: &'b CentroidDecomposition<'a>) -> Self {
+        Self {
+            cd,
+            marked: vec![BTreeSet::new(); cd.graph.vertices_size()],
+        }
+    }
+    pub fn mark(&mut self, u: usize) {
+        for (c, d) in self.cd.ancestors(u) {
+            self.marked[c].insert((d, u));
+        }
+    }
+    pub fn unmark(&mut self, u: usize) {
+        for (c, d) in self.cd.ancestors(u) {
+            self.marked[c].remove(&(d, u));
+        }
+    }
+    pub fn is_marked(&self, u: usize) -> bool {
+        self.marked[u].contains(&(0, u))
+    }
+    /// (distance, vertex) of the nearest marked vertex in the same tree
+    pub fn nearest(&self, u: usize) -> Option<(usize, usize)> {
+        self.cd
+            .ancestors(u)
+            .filter_map(|(c, d)| self.marked[c].iter().next().map(|&(e, v)| (d + e, v)))
+            .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rand, tools::Xorshift, tree::MixedTree};
+
+    fn all_dist(g: &UndirectedSparseGraph) -> Vec<Vec<usize>> {
+        let n = g.vertices_size();
+        (0..n)
+            .map(|s| {
+                let mut d = vec![!0usize; n];
+                d[s] = 0;
+                let mut queue = vec![s];
+                let mut i = 0;
+                while i < queue.len() {
+                    let u = queue[i];
+                    i += 1;
+                    for a in g.adjacencies(u) {
+                        if d[a.to] == !0 {
+                            d[a.to] = d[u] + 1;
+                            queue.push(a.to);
+                        }
+                    }
+                }
+                d
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_centroid_decomposition() {
+        const Q: usize = 200;
+        const N: usize = 100;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), k: (1..=3));
+            // forest of `k` trees
+            let mut edges = vec![];
+            let mut offset = 0;
+            for i in 0..k {
+                let m = if i + 1 == k {
+                    n - offset
+                } else {
+                    rng.gen(0..=n - offset)
+                };
+                let g = rng.gen(MixedTree(m));
+                edges.extend(g.edges.iter().map(|&(u, v)| (u + offset, v + offset)));
+                offset += m;
+            }
+            let g = UndirectedSparseGraph::from_edges(n, edges);
+            let d = all_dist(&g);
+            let cd = CentroidDecomposition::new(&g);
+            let mut order = cd.order().to_vec();
+            order.sort_unstable();
+            assert_eq!(order, (0..n).collect::<Vec<_>>());
+            let tree = cd.centroid_tree();
+            for (u, du) in d.iter().enumerate() {
+                assert!(1 << cd.level(u) <= n);
+                if let Some(p) = cd.parent(u) {
+                    assert_eq!(cd.level(p) + 1, cd.level(u));
+                    assert!(tree.adjacencies(u).any(|a| a.to == p));
+                }
+                let ancestors: Vec<_> = cd.ancestors(u).collect();
+                assert_eq!(ancestors.len(), cd.level(u) + 1);
+                assert_eq!(ancestors[0], (u, 0));
+                for (i, &(c, dist)) in ancestors.iter().rev().enumerate() {
+                    assert_eq!(cd.level(c), i);
+                    assert_eq!(dist, du[c]);
+                    assert_eq!(cd.dist(u, i), dist);
+                }
+            }
+
+            // count pairs by distance
+            let mut expected = vec![0usize; n];
+            for u in 0..n {
+                for v in 0..u {
+                    if d[u][v] != !0 {
+                        expected[d[u][v]] += 1;
+                    }
+                }
+            }
+            let mut count = vec![0usize; n];
+            cd.for_each_centroid(|c, subtrees| {
+                let lv = cd.level(c);
+                let mut all = vec![0usize; n];
+                all[0] = 1;
+                for sub in subtrees.iter() {
+                    let mut cur = vec![0usize; n];
+                    for &(v, p, eid) in sub.iter() {
+                        assert_eq!(cd.dist(v, lv), cd.dist(p, lv) + 1);
+                        let (x, y) = g.edges[eid];
+                        assert!((x, y) == (v, p) || (x, y) == (p, v));
+                        cur[cd.dist(v, lv)] += 1;
+                    }
+                    for i in 0..n {
+                        for j in 0..n - i {
+                            count[i + j] += all[i] * cur[j];
+                        }
+                    }
+                    for i in 0..n {
+                        all[i] += cur[i];
+                    }
+                }
+            });
+            assert_eq!(count, expected);
+        }
+    }
+
+    #[test]
+    fn test_nearest_marked_vertex() {
+        const Q: usize = 100;
+        const N: usize = 60;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N));
+            let g = rng.gen(MixedTree(n));
+            let d = all_dist(&g);
+            let cd = CentroidDecomposition::new(&g);
+            let mut nmv = NearestMarkedVertex::new(&cd);
+            let mut marked = vec![false; n];
+            for _ in 0..n * 4 {
+                rand!(rng, u: (0..n));
+                match rng.gen(0..3) {
+                    0 => {
+                        if !marked[u] {
+                            nmv.mark(u);
+                            marked[u] = true;
+                        }
+                    }
+                    1 => {
+                        if marked[u] {
+                            nmv.unmark(u);
+                            marked[u] = false;
+                        }
+                    }
+                    _ => {
+                        let expected = (0..n).filter(|&v| marked[v]).map(|v| d[u][v]).min();
+                        let res = nmv.nearest(u);
+                        assert_eq!(res.map(|r| r.0), expected);
+                        if let Some((dist, v)) = res {
+                            assert!(marked[v]);
+                            assert_eq!(d[u][v], dist);
+                        }
+                    }
+                }
+                assert_eq!(nmv.is_marked(u), marked[u]);
+            }
+        }
+    }
+}
@@ -164,6 +164,9 @@ impl<'a, D: LcaMonoidDispatch> LowestCommonAncestor<'a, D> {
     pub fn lca(&self, u: usize, v: usize) -> usize {
         self.euler.query(u, v, |l, r| self.dst.fold(l, r))
     }
+    pub fn euler(&self) -> &'a EulerTourForRichVertex<'a> {
+        self.euler
+    }
 }
 #[codesnip::entry("LowestCommonAncestor")]
 pub trait LcaMonoidDispatch {
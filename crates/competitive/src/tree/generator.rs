@@ -36,6 +36,58 @@ impl<T: RandomSpec<usize>> RandomSpec<UndirectedSparseGraph> for StarTree<T> {
     }
 }
 
+/// Path of random length with leaves attached to random vertices of the path
+pub struct CaterpillarTree<T>(pub T);
+
+impl<T: RandomSpec<usize>> RandomSpec<UndirectedSparseGraph> for CaterpillarTree<T> {
+    fn rand(&self, rng: &mut Xorshift) -> UndirectedSparseGraph {
+        let n = rng.gen(&self.0);
+        let mut edges = Vec::with_capacity(n.saturating_sub(1));
+        if n >= 1 {
+            let k = rng.gen(1..=n);
+            edges.extend((1..k).map(|u| (u - 1, u)));
+            edges.extend((k..n).map(|u| (rng.gen(0..k), u)));
+        }
+        UndirectedSparseGraph::from_edges(n, edges)
+    }
+}
+
+/// Random binary tree rooted at `0`
+pub struct BinaryTree<T>(pub T);
+
+impl<T: RandomSpec<usize>> RandomSpec<UndirectedSparseGraph> for BinaryTree<T> {
+    fn rand(&self, rng: &mut Xorshift) -> UndirectedSparseGraph {
+        let n = rng.gen(&self.0);
+        let mut edges = Vec::with_capacity(n.saturating_sub(1));
+        // vertices with less than two children, each appears once per free slot
+        let mut slots = vec![0usize; 2.min(n)];
+        for u in 1..n {
+            let i = rng.gen(0..slots.len());
+            let p = slots.swap_remove(i);
+            edges.push((p, u));
+            slots.push(u);
+            slots.push(u);
+        }
+        UndirectedSparseGraph::from_edges(n, edges)
+    }
+}
+
+/// Path of random length with a star at the end
+pub struct BroomTree<T>(pub T);
+
+impl<T: RandomSpec<usize>> RandomSpec<UndirectedSparseGraph> for BroomTree<T> {
+    fn rand(&self, rng: &mut Xorshift) -> UndirectedSparseGraph {
+        let n = rng.gen(&self.0);
+        let mut edges = Vec::with_capacity(n.saturating_sub(1));
+        if n >= 1 {
+            let k = rng.gen(1..=n);
+            edges.extend((1..k).map(|u| (u - 1, u)));
+            edges.extend((k..n).map(|u| (k - 1, u)));
+        }
+        UndirectedSparseGraph::from_edges(n, edges)
+    }
+}
+
 pub struct MixedTree<T>(pub T);
 
 impl<T: RandomSpec<usize>> RandomSpec<UndirectedSparseGraph> for MixedTree<T> {
@@ -187,6 +239,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn caterpillar_binary_broom() {
+        const Q: usize = 1000;
+        const N: usize = 20;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            let g = rng.gen(CaterpillarTree(0..=N));
+            assert!(is_tree(&g));
+            let g = rng.gen(BinaryTree(0..=N));
+            assert!(is_tree(&g));
+            assert!(g
+                .vertices()
+                .all(|u| g.adjacencies(u).len() <= 2 + (u != 0) as usize));
+            let g = rng.gen(BroomTree(0..=N));
+            assert!(is_tree(&g));
+        }
+    }
+
     #[test]
     fn mixed_small() {
         const Q: usize = 10_000;
@@ -1,61 +1,144 @@
 use crate::algebra::Monoid;
 use crate::graph::UndirectedSparseGraph;
 
-#[codesnip::entry("HeavyLightDecomposition", include("algebra", "SparseGraph"))]
+#[codesnip::entry(
+    "HeavyLightDecomposition",
+    include("algebra", "SparseGraph", "tree_order")
+)]
+#[derive(Clone, Debug)]
+/// heavy-light decomposition, `vidx` is a dfs preorder visiting heavy children first
 pub struct HeavyLightDecomposition {
+    /// parent, `n` for the root
     pub par: Vec<usize>,
     size: Vec<usize>,
+    depth: Vec<usize>,
     head: Vec<usize>,
     pub vidx: Vec<usize>,
+    /// vertex of each index
+    inv: Vec<usize>,
 }
 #[codesnip::entry("HeavyLightDecomposition")]
 impl HeavyLightDecomposition {
-    pub fn new(root: usize, graph: &mut UndirectedSparseGraph) -> Self {
-        let mut self_ = Self {
-            par: vec![0; graph.vertices_size()],
-            size: vec![0; graph.vertices_size()],
-            head: vec![0; graph.vertices_size()],
-            vidx: vec![0; graph.vertices_size()],
-        };
-        self_.build(root, graph);
-        self_
-    }
-    fn dfs_size(&mut self, u: usize, p: usize, graph: &mut UndirectedSparseGraph) {
-        self.par[u] = p;
-        self.size[u] = 1;
-        let base = graph.start[u];
-        if graph.adjacencies(u).len() > 1 && graph.adjacencies(u).next().unwrap().to == p {
-            graph.elist.swap(base, base + 1);
+    pub fn new(root: usize, graph: &UndirectedSparseGraph) -> Self {
+        let n = graph.vertices_size();
+        let (order, mut par) = graph.tree_order(root);
+        par[root] = n;
+        let mut size = vec![1usize; n];
+        let mut heavy = vec![!0usize; n];
+        for &u in order.iter().skip(1).rev() {
+            size[par[u]] += size[u];
         }
-        for i in base..graph.start[u + 1] {
-            let a = graph.elist[i];
-            if a.to != p {
-                self.dfs_size(a.to, u, graph);
-                self.size[u] += self.size[a.to];
-                if self.size[graph.elist[base].to] < self.size[a.to] {
-                    graph.elist.swap(base, i);
+        for &u in order.iter().skip(1) {
+            let p = par[u];
+            if heavy[p] == !0 || size[heavy[p]] < size[u] {
+                heavy[p] = u;
+            }
+        }
+        let mut depth = vec![0usize; n];
+        let mut head = vec![root; n];
+        let mut vidx = vec![0usize; n];
+        let mut inv = Vec::with_capacity(n);
+        let mut stack = vec![root];
+        while let Some(u) = stack.pop() {
+            vidx[u] = inv.len();
+            inv.push(u);
+            for a in graph.adjacencies(u) {
+                if a.to != par[u] && a.to != heavy[u] {
+                    depth[a.to] = depth[u] + 1;
+                    head[a.to] = a.to;
+                    stack.push(a.to);
                 }
             }
+            if heavy[u] != !0 {
+                depth[heavy[u]] = depth[u] + 1;
+                head[heavy[u]] = head[u];
+                stack.push(heavy[u]);
+            }
+        }
+        Self {
+            par,
+            size,
+            depth,
+            head,
+            vidx,
+            inv,
+        }
+    }
+    pub fn depth(&self, u: usize) -> usize {
+        self.depth[u]
+    }
+    /// range of indices in the subtree of `u`
+    pub fn subtree_range(&self, u: usize) -> (usize, usize) {
+        (self.vidx[u], self.vidx[u] + self.size[u])
+    }
+    /// index of the edge `(u, v)` in edge mode, placed on the child vertex
+    pub fn edge_index(&self, u: usize, v: usize) -> usize {
+        if self.par[u] == v {
+            self.vidx[u]
+        } else {
+            debug_assert_eq!(self.par[v], u);
+            self.vidx[v]
         }
     }
-    fn dfs_hld(&mut self, u: usize, p: usize, t: &mut usize, graph: &UndirectedSparseGraph) {
-        self.vidx[u] = *t;
-        *t += 1;
-        let mut adjacencies = graph.adjacencies(u).filter(|a| a.to != p);
-        if let Some(a) = adjacencies.next() {
-            self.head[a.to] = self.head[u];
-            self.dfs_hld(a.to, u, t, graph);
+    /// `k`-th ancestor of `u`
+    pub fn la(&self, mut u: usize, mut k: usize) -> Option<usize> {
+        if k > self.depth[u] {
+            return None;
         }
-        for a in adjacencies {
-            self.head[a.to] = a.to;
-            self.dfs_hld(a.to, u, t, graph);
+        loop {
+            let h = self.head[u];
+            let d = self.vidx[u] - self.vidx[h];
+            if k <= d {
+                return Some(self.inv[self.vidx[u] - k]);
+            }
+            k -= d + 1;
+            u = self.par[h];
         }
     }
-    fn build(&mut self, root: usize, graph: &mut UndirectedSparseGraph) {
-        self.head[root] = root;
-        self.dfs_size(root, graph.vertices_size(), graph);
-        let mut t = 0;
-        self.dfs_hld(root, graph.vertices_size(), &mut t, graph);
+    pub fn dist(&self, u: usize, v: usize) -> usize {
+        self.depth[u] + self.depth[v] - self.depth[self.lca(u, v)] * 2
+    }
+    /// `k`-th vertex on the path from `u` to `v`
+    pub fn jump(&self, u: usize, v: usize, k: usize) -> Option<usize> {
+        let l = self.lca(u, v);
+        let (du, dv) = (self.depth[u] - self.depth[l], self.depth[v] - self.depth[l]);
+        if k <= du {
+            self.la(u, k)
+        } else if k <= du + dv {
+            self.la(v, du + dv - k)
+        } else {
+            None
+        }
+    }
+    /// segments `(l, r, reversed)` of indices on the path from `u` to `v` in this order,
+    /// where `reversed` segments are traversed from `r - 1` down to `l`
+    ///
+    /// The LCA is excluded if `is_edge`.
+    pub fn path_segments(
+        &self,
+        mut u: usize,
+        mut v: usize,
+        is_edge: bool,
+    ) -> impl Iterator<Item = (usize, usize, bool)> {
+        let (mut up, mut down) = (vec![], vec![]);
+        while self.head[u] != self.head[v] {
+            if self.vidx[u] > self.vidx[v] {
+                up.push((self.vidx[self.head[u]], self.vidx[u] + 1, true));
+                u = self.par[self.head[u]];
+            } else {
+                down.push((self.vidx[self.head[v]], self.vidx[v] + 1, false));
+                v = self.par[self.head[v]];
+            }
+        }
+        let last = if self.vidx[u] > self.vidx[v] {
+            (self.vidx[v] + is_edge as usize, self.vidx[u] + 1, true)
+        } else {
+            (self.vidx[u] + is_edge as usize, self.vidx[v] + 1, false)
+        };
+        if last.0 < last.1 {
+            up.push(last);
+        }
+        up.into_iter().chain(down.into_iter().rev())
     }
     pub fn lca(&self, mut u: usize, mut v: usize) -> usize {
         loop {
@@ -146,3 +229,75 @@ impl HeavyLightDecomposition {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        rand,
+        tools::Xorshift,
+        tree::{LevelAncestor, MixedTree},
+    };
+
+    #[test]
+    fn test_heavy_light_decomposition() {
+        const Q: usize = 100;
+        const N: usize = 60;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), root: (0..n));
+            let g = rng.gen(MixedTree(n));
+            let hld = HeavyLightDecomposition::new(root, &g);
+            let la = LevelAncestor::from_tree(&g, root);
+            let mut inv = vec![!0usize; n];
+            for u in 0..n {
+                inv[hld.vidx[u]] = u;
+            }
+            for u in 0..n {
+                assert_eq!(hld.depth(u), la.depth(u));
+                assert_eq!(hld.par[u], la.parent(u).unwrap_or(n));
+                let (l, r) = hld.subtree_range(u);
+                for (i, &v) in inv.iter().enumerate() {
+                    let is_descendant = la.depth(v) >= la.depth(u)
+                        && la.la(v, la.depth(v) - la.depth(u)) == Some(u);
+                    assert_eq!(l <= i && i < r, is_descendant);
+                }
+                if let Some(p) = la.parent(u) {
+                    assert_eq!(hld.edge_index(u, p), hld.vidx[u]);
+                    assert_eq!(hld.edge_index(p, u), hld.vidx[u]);
+                }
+                for k in 0..=n {
+                    assert_eq!(hld.la(u, k), la.la(u, k));
+                }
+                for v in 0..n {
+                    assert_eq!(hld.lca(u, v), la.lca(u, v));
+                    assert_eq!(hld.dist(u, v), la.dist(u, v));
+                    for k in 0..=n {
+                        assert_eq!(hld.jump(u, v, k), la.jump(u, v, k));
+                    }
+                    let path: Vec<_> = (0..=la.dist(u, v))
+                        .map(|k| la.jump(u, v, k).unwrap())
+                        .collect();
+                    for &is_edge in [false, true].iter() {
+                        let mut res = vec![];
+                        for (l, r, rev) in hld.path_segments(u, v, is_edge) {
+                            assert!(l < r);
+                            if rev {
+                                res.extend((l..r).rev().map(|i| inv[i]));
+                            } else {
+                                res.extend((l..r).map(|i| inv[i]));
+                            }
+                        }
+                        let lca = la.lca(u, v);
+                        let expected: Vec<_> = path
+                            .iter()
+                            .cloned()
+                            .filter(|&w| !is_edge || w != lca)
+                            .collect();
+                        assert_eq!(res, expected);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use crate::graph::UndirectedSparseGraph;
+
+#[codesnip::entry("LevelAncestor", include("tree_order"))]
+/// O(1) level ancestor by jump pointers and ladder decomposition, O(n log n) preprocessing
+#[derive(Debug, Clone)]
+pub struct LevelAncestor {
+    depth: Vec<usize>,
+    jump: Vec<Vec<usize>>,
+    ladders: Vec<Vec<usize>>,
+    /// (ladder id, index in the ladder)
+    pos: Vec<(usize, usize)>,
+}
+#[codesnip::entry("LevelAncestor")]
+impl LevelAncestor {
+    /// forest given by parents, `!0` for roots
+    pub fn new(parent: &[usize]) -> Self {
+        let n = parent.len();
+        let mut children = vec![vec![]; n];
+        let mut order = Vec::with_capacity(n);
+        for (v, &p) in parent.iter().enumerate() {
+            if p == !0 {
+                order.push(v);
+            } else {
+                children[p].push(v);
+            }
+        }
+        let mut depth = vec![0usize; n];
+        let mut i = 0;
+        while i < order.len() {
+            let u = order[i];
+            i += 1;
+            for &v in children[u].iter() {
+                depth[v] = depth[u] + 1;
+                order.push(v);
+            }
+        }
+        assert_eq!(order.len(), n, "parent must form a forest");
+        // height[v]: number of vertices on the longest downward path from v
+        let mut height = vec![1usize; n];
+        let mut heavy = vec![!0usize; n];
+        for &v in order.iter().rev() {
+            let p = parent[v];
+            if p != !0 && height[p] < height[v] + 1 {
+                height[p] = height[v] + 1;
+                heavy[p] = v;
+            }
+        }
+        let mut ladders = vec![];
+        let mut pos = vec![(!0, !0); n];
+        for &top in order.iter() {
+            if parent[top] != !0 && heavy[parent[top]] == top {
+                continue;
+            }
+            let mut path = vec![];
+            let mut v = top;
+            while v != !0 {
+                path.push(v);
+                v = heavy[v];
+            }
+            // extend upward by the length of the path
+            let mut ladder = vec![];
+            let mut v = parent[top];
+            while v != !0 && ladder.len() < path.len() {
+                ladder.push(v);
+                v = parent[v];
+            }
+            ladder.reverse();
+            for &v in path.iter() {
+                pos[v] = (ladders.len(), ladder.len());
+                ladder.push(v);
+            }
+            ladders.push(ladder);
+        }
+        let mut jump = vec![parent.to_vec()];
+        while 1 << jump.len() < n {
+            let prev = jump.last().unwrap();
+            let next = prev
+                .iter()
+                .map(|&p| if p == !0 { !0 } else { prev[p] })
+                .collect();
+            jump.push(next);
+        }
+        Self {
+            depth,
+            jump,
+            ladders,
+            pos,
+        }
+    }
+    /// rooted tree
+    pub fn from_tree(graph: &UndirectedSparseGraph, root: usize) -> Self {
+        Self::new(&graph.tree_order(root).1)
+    }
+    pub fn depth(&self, u: usize) -> usize {
+        self.depth[u]
+    }
+    pub fn parent(&self, u: usize) -> Option<usize> {
+        self.la(u, 1)
+    }
+    /// `k`-th ancestor of `u`
+    pub fn la(&self, u: usize, k: usize) -> Option<usize> {
+        if k > self.depth[u] {
+            return None;
+        }
+        if k == 0 {
+            return Some(u);
+        }
+        let j = std::mem::size_of::<usize>() * 8 - 1 - k.leading_zeros() as usize;
+        let u = self.jump[j][u];
+        let (l, i) = self.pos[u];
+        Some(self.ladders[l][i - (k - (1 << j))])
+    }
+    /// `u` and `v` must be in the same tree
+    pub fn lca(&self, u: usize, v: usize) -> usize {
+        let (mut u, mut v) = if self.depth[u] >= self.depth[v] {
+            (self.la(u, self.depth[u] - self.depth[v]).unwrap(), v)
+        } else {
+            (u, self.la(v, self.depth[v] - self.depth[u]).unwrap())
+        };
+        if u == v {
+            return u;
+        }
+        for jump in self.jump.iter().rev() {
+            if jump[u] != jump[v] {
+                u = jump[u];
+                v = jump[v];
+            }
+        }
+        self.jump[0][u]
+    }
+    pub fn dist(&self, u: usize, v: usize) -> usize {
+        self.depth[u] + self.depth[v] - self.depth[self.lca(u, v)] * 2
+    }
+    /// `k`-th vertex on the path from `u` to `v`
+    pub fn jump(&self, u: usize, v: usize, k: usize) -> Option<usize> {
+        let l = self.lca(u, v);
+        let (du, dv) = (self.depth[u] - self.depth[l], self.depth[v] - self.depth[l]);
+        if k <= du {
+            self.la(u, k)
+        } else if k <= du + dv {
+            self.la(v, du + dv - k)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rand, tools::Xorshift};
+
+    #[test]
+    fn test_level_ancestor() {
+        const Q: usize = 200;
+        const N: usize = 100;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N));
+            let parent: Vec<_> = (0..n)
+                .map(|v| {
+                    if v == 0 || rng.gen_bool(0.05) {
+                        !0
+                    } else {
+                        rng.gen(v.saturating_sub(3)..v)
+                    }
+                })
+                .collect();
+            let la = LevelAncestor::new(&parent);
+            for u in 0..n {
+                let mut v = u;
+                for k in 0..=n {
+                    assert_eq!(la.la(u, k), if v == !0 { None } else { Some(v) });
+                    if v != !0 {
+                        assert_eq!(la.depth(u), la.depth(v) + k);
+                        v = parent[v];
+                    }
+                }
+            }
+        }
+    }
+}
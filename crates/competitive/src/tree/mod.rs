@@ -5,14 +5,33 @@ use crate::{
     tools::{RandomSpec, Xorshift},
 };
 
+#[codesnip::entry("AuxiliaryTree")]
+pub use self::auxiliary_tree::{AuxiliaryTreeBuilder, LcaProvider};
+#[codesnip::entry("BinaryLifting")]
+pub use self::binary_lifting::BinaryLifting;
+#[codesnip::entry("CentroidDecomposition")]
+pub use self::centroid_decomposition::{CentroidDecomposition, NearestMarkedVertex};
 pub use self::euler_tour::*;
 #[codesnip::entry("tree_generator")]
 pub use self::generator::*;
 pub use self::heavy_light_decomposition::*;
+#[codesnip::entry("LevelAncestor")]
+pub use self::level_ancestor::LevelAncestor;
 pub use self::rerooting::ReRooting;
+#[codesnip::entry("StaticTopTree")]
+pub use self::static_top_tree::{StaticTopTree, StaticTopTreeDp, TopTreeDp};
 pub use self::tree_center::*;
 pub use self::tree_hash::TreeHasher;
+#[codesnip::entry("TreeIsomorphism")]
+pub use self::tree_isomorphism::TreeIsomorphism;
 
+mod auxiliary_tree;
+mod binary_lifting;
+#[cfg_attr(
+    nightly,
+    codesnip::entry("CentroidDecomposition", include("SparseGraph"))
+)]
+mod centroid_decomposition;
 mod depth;
 mod euler_tour;
 #[cfg_attr(
@@ -21,8 +40,15 @@ mod euler_tour;
 )]
 mod generator;
 mod heavy_light_decomposition;
+mod level_ancestor;
 mod rerooting;
+mod static_top_tree;
 mod tree_center;
 mod tree_dp;
 mod tree_hash;
+#[cfg_attr(
+    nightly,
+    codesnip::entry("TreeIsomorphism", include("tree_center", "tree_order"))
+)]
+mod tree_isomorphism;
 mod tree_order;
@@ -0,0 +1,330 @@
+use crate::graph::UndirectedSparseGraph;
+
+#[codesnip::entry("StaticTopTree")]
+/// operations of clusters on a static top tree
+///
+/// A path cluster is a heavy path segment with its hanging subtrees, whose boundary is the
+/// top vertex and the heavy child of the bottom vertex.
+/// A point cluster is a set of subtrees hanging on one vertex.
+/// Edge weights can be placed on child vertices.
+pub trait TopTreeDp {
+    /// vertex value
+    type V;
+    type Path: Clone;
+    type Point: Clone;
+    /// path cluster of a single vertex without hanging subtrees
+    fn vertex(x: &Self::V) -> Self::Path;
+    /// path cluster of a vertex with hanging subtrees
+    fn add_vertex(t: &Self::Point, x: &Self::V) -> Self::Path;
+    /// hang a path cluster on its parent
+    fn add_edge(p: &Self::Path) -> Self::Point;
+    fn rake(l: &Self::Point, r: &Self::Point) -> Self::Point;
+    /// concatenate the upper path `p` and the lower path `c`
+    fn compress(p: &Self::Path, c: &Self::Path) -> Self::Path;
+}
+
+#[codesnip::entry("StaticTopTree")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TopTreeNode {
+    Vertex(usize),
+    AddVertex(usize, usize),
+    AddEdge(usize),
+    Rake(usize, usize),
+    Compress(usize, usize),
+}
+
+#[codesnip::entry("StaticTopTree", include("tree_order"))]
+/// static top tree of a rooted tree with O(log n) depth
+#[derive(Debug, Clone)]
+pub struct StaticTopTree {
+    /// children precede parents
+    nodes: Vec<TopTreeNode>,
+    parent: Vec<usize>,
+    /// node containing each vertex
+    vnode: Vec<usize>,
+}
+#[codesnip::entry("StaticTopTree")]
+impl StaticTopTree {
+    pub fn new(graph: &UndirectedSparseGraph, root: usize) -> Self {
+        let n = graph.vertices_size();
+        let (order, par) = graph.tree_order(root);
+        let mut size = vec![1usize; n];
+        let mut heavy = vec![!0usize; n];
+        for &v in order.iter().rev() {
+            let p = par[v];
+            if p != !0 {
+                size[p] += size[v];
+                if heavy[p] == !0 || size[heavy[p]] < size[v] {
+                    heavy[p] = v;
+                }
+            }
+        }
+        let mut light = vec![vec![]; n];
+        for &v in order.iter() {
+            let p = par[v];
+            if p != !0 && heavy[p] != v {
+                light[p].push(v);
+            }
+        }
+        let mut builder = Builder {
+            tree: Self {
+                nodes: Vec::with_capacity(n * 4),
+                parent: Vec::with_capacity(n * 4),
+                vnode: vec![!0; n],
+            },
+            size,
+            heavy,
+            light,
+        };
+        builder.compress(root);
+        builder.tree
+    }
+    pub fn dp<D>(&self, value: Vec<D::V>) -> StaticTopTreeDp<'_, D>
+    where
+        D: TopTreeDp,
+    {
+        StaticTopTreeDp::new(self, value)
+    }
+}
+
+#[codesnip::entry("StaticTopTree")]
+struct Builder {
+    tree: StaticTopTree,
+    size: Vec<usize>,
+    heavy: Vec<usize>,
+    light: Vec<Vec<usize>>,
+}
+#[codesnip::entry("StaticTopTree")]
+impl Builder {
+    fn push(&mut self, node: TopTreeNode) -> usize {
+        let k = self.tree.nodes.len();
+        match node {
+            TopTreeNode::Vertex(_) => {}
+            TopTreeNode::AddVertex(_, c) | TopTreeNode::AddEdge(c) => self.tree.parent[c] = k,
+            TopTreeNode::Rake(l, r) | TopTreeNode::Compress(l, r) => {
+                self.tree.parent[l] = k;
+                self.tree.parent[r] = k;
+            }
+        }
+        self.tree.nodes.push(node);
+        self.tree.parent.push(!0);
+        k
+    }
+    /// merge (node, weight) by splitting at the weighted midpoint
+    fn merge(&mut self, list: &[(usize, usize)], compress: bool) -> usize {
+        if list.len() == 1 {
+            return list[0].0;
+        }
+        let total: usize = list.iter().map(|&(_, w)| w).sum();
+        let mut i = 1;
+        let mut acc = list[0].1;
+        while i + 1 < list.len() && (acc + list[i].1) * 2 <= total {
+            acc += list[i].1;
+            i += 1;
+        }
+        let l = self.merge(&list[..i], compress);
+        let r = self.merge(&list[i..], compress);
+        self.push(if compress {
+            TopTreeNode::Compress(l, r)
+        } else {
+            TopTreeNode::Rake(l, r)
+        })
+    }
+    /// path cluster of the heavy path from `v`
+    fn compress(&mut self, v: usize) -> usize {
+        let mut list = vec![];
+        let mut w = v;
+        while w != !0 {
+            let node = if self.light[w].is_empty() {
+                self.push(TopTreeNode::Vertex(w))
+            } else {
+                let t = self.rake(w);
+                self.push(TopTreeNode::AddVertex(w, t))
+            };
+            self.tree.vnode[w] = node;
+            let h = self.heavy[w];
+            list.push((node, self.size[w] - if h == !0 { 0 } else { self.size[h] }));
+            w = h;
+        }
+        self.merge(&list, true)
+    }
+    /// point cluster of light children of `v`
+    fn rake(&mut self, v: usize) -> usize {
+        let children = std::mem::take(&mut self.light[v]);
+        let mut list = Vec::with_capacity(children.len());
+        for &c in children.iter() {
+            let p = self.compress(c);
+            list.push((self.push(TopTreeNode::AddEdge(p)), self.size[c]));
+        }
+        self.light[v] = children;
+        self.merge(&list, false)
+    }
+}
+
+#[codesnip::entry("StaticTopTree")]
+/// dp on a [`StaticTopTree`] with O(log n) point updates
+#[derive(Debug, Clone)]
+pub struct StaticTopTreeDp<'a, D>
+where
+    D: TopTreeDp,
+{
+    tree: &'a StaticTopTree,
+    value: Vec<D::V>,
+    path: Vec<Option<D::Path>>,
+    point: Vec<Option<D::Point>>,
+}
+#[codesnip::entry("StaticTopTree")]
+impl<'a, D> StaticTopTreeDp<'a, D>
+where
+    D: TopTreeDp,
+{
+    pub fn new(tree: &'a StaticTopTree, value: Vec<D::V>) -> Self {
+        assert_eq!(tree.vnode.len(), value.len());
+        let m = tree.nodes.len();
+        let mut self_ = Self {
+            tree,
+            value,
+            path: vec![None; m],
+            point: vec![None; m],
+        };
+        for k in 0..m {
+            self_.calc(k);
+        }
+        self_
+    }
+    fn calc(&mut self, k: usize) {
+        match self.tree.nodes[k] {
+            TopTreeNode::Vertex(v) => self.path[k] = Some(D::vertex(&self.value[v])),
+            TopTreeNode::AddVertex(v, c) => {
+                self.path[k] = Some(D::add_vertex(
+                    self.point[c].as_ref().unwrap(),
+                    &self.value[v],
+                ))
+            }
+            TopTreeNode::AddEdge(c) => {
+                self.point[k] = Some(D::add_edge(self.path[c].as_ref().unwrap()))
+            }
+            TopTreeNode::Rake(l, r) => {
+                self.point[k] = Some(D::rake(
+                    self.point[l].as_ref().unwrap(),
+                    self.point[r].as_ref().unwrap(),
+                ))
+            }
+            TopTreeNode::Compress(l, r) => {
+                self.path[k] = Some(D::compress(
+                    self.path[l].as_ref().unwrap(),
+                    self.path[r].as_ref().unwrap(),
+                ))
+            }
+        }
+    }
+    pub fn get(&self, v: usize) -> &D::V {
+        &self.value[v]
+    }
+    /// set the value of `v` in O(log n)
+    pub fn set(&mut self, v: usize, x: D::V) {
+        self.value[v] = x;
+        let mut k = self.tree.vnode[v];
+        while k != !0 {
+            self.calc(k);
+            k = self.tree.parent[k];
+        }
+    }
+    /// path cluster of the whole tree
+    pub fn fold(&self) -> &D::Path {
+        self.path.last().unwrap().as_ref().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rand, tools::Xorshift, tree::MixedTree};
+
+    #[allow(clippy::legacy_numeric_constants)]
+    const NEG: i64 = std::i64::MIN / 4;
+
+    /// maximum weight independent set
+    enum Mwis {}
+    impl TopTreeDp for Mwis {
+        type V = i64;
+        /// [top is chosen][bottom is chosen]
+        type Path = [[i64; 2]; 2];
+        /// (parent is not chosen, parent is chosen)
+        type Point = (i64, i64);
+        fn vertex(x: &i64) -> Self::Path {
+            Self::add_vertex(&(0, 0), x)
+        }
+        fn add_vertex(t: &Self::Point, x: &i64) -> Self::Path {
+            [[t.0, NEG], [NEG, t.1 + x]]
+        }
+        fn add_edge(p: &Self::Path) -> Self::Point {
+            (
+                p.iter().flatten().cloned().max().unwrap(),
+                p[0].iter().cloned().max().unwrap(),
+            )
+        }
+        fn rake(l: &Self::Point, r: &Self::Point) -> Self::Point {
+            (l.0 + r.0, l.1 + r.1)
+        }
+        fn compress(p: &Self::Path, c: &Self::Path) -> Self::Path {
+            let mut res = [[NEG; 2]; 2];
+            for a in 0..2 {
+                for d in 0..2 {
+                    for (b, x) in p[a].iter().enumerate() {
+                        for (e, y) in c.iter().enumerate() {
+                            if b + e < 2 {
+                                res[a][d] = res[a][d].max(x + y[d]);
+                            }
+                        }
+                    }
+                }
+            }
+            res
+        }
+    }
+
+    #[test]
+    fn test_static_top_tree() {
+        const Q: usize = 100;
+        const N: usize = 100;
+        let mut rng = Xorshift::default();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), root: (0..n), mut w: [-100i64..100; n]);
+            let g = rng.gen(MixedTree(n));
+            let (order, parent) = g.tree_order(root);
+            let brute = |w: &[i64]| {
+                let mut dp = vec![(0i64, 0i64); n];
+                for &v in order.iter().rev() {
+                    dp[v].1 += w[v];
+                    let p = parent[v];
+                    if p != !0 {
+                        dp[p].0 += dp[v].0.max(dp[v].1);
+                        dp[p].1 += dp[v].0;
+                    }
+                }
+                dp[root].0.max(dp[root].1)
+            };
+            let tree = StaticTopTree::new(&g, root);
+            let mut depth = vec![0usize; tree.nodes.len()];
+            for k in (0..tree.nodes.len()).rev() {
+                if tree.parent[k] != !0 {
+                    depth[k] = depth[tree.parent[k]] + 1;
+                }
+                assert!(
+                    depth[k]
+                        <= 4 * (std::mem::size_of::<usize>() * 8 - n.leading_zeros() as usize) + 2
+                );
+            }
+            let mut dp = tree.dp::<Mwis>(w.clone());
+            assert_eq!(dp.fold().iter().flatten().max(), Some(&brute(&w)));
+            for _ in 0..n {
+                rand!(rng, v: (0..n), x: (-100i64..100));
+                w[v] = x;
+                dp.set(v, x);
+                assert_eq!(dp.get(v), &x);
+                assert_eq!(dp.fold().iter().flatten().max(), Some(&brute(&w)));
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{graph::UndirectedSparseGraph, tree::TreeCenter};
+use std::collections::HashMap;
+
+/// deterministic AHU canonical ids of rooted and unrooted trees
+///
+/// Ids are shared among all trees given to the same instance and assigned densely from `0`.
+#[derive(Debug, Clone, Default)]
+pub struct TreeIsomorphism {
+    /// sorted ids of children -> id of the rooted tree
+    rooted: HashMap<Vec<usize>, usize>,
+    /// ids of rooted trees at the centers -> id of the unrooted tree
+    unrooted: HashMap<Vec<usize>, usize>,
+}
+impl TreeIsomorphism {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// number of classes of rooted trees
+    pub fn rooted_classes(&self) -> usize {
+        self.rooted.len()
+    }
+    /// number of classes of unrooted trees
+    pub fn unrooted_classes(&self) -> usize {
+        self.unrooted.len()
+    }
+    fn intern(map: &mut HashMap<Vec<usize>, usize>, key: Vec<usize>) -> usize {
+        let k = map.len();
+        *map.entry(key).or_insert(k)
+    }
+    /// `order` must visit parents before children
+    fn ids_by_order(&mut self, order: &[usize], parent: &[usize], id: &mut [usize]) {
+        let mut children = vec![vec![]; id.len()];
+        for &u in order.iter().rev() {
+            let mut ch = std::mem::take(&mut children[u]);
+            ch.sort_unstable();
+            id[u] = Self::intern(&mut self.rooted, ch);
+            if parent[u] != !0 {
+                children[parent[u]].push(id[u]);
+            }
+        }
+    }
+    /// ids of all subtrees of a rooted forest given by parents, `!0` for roots
+    pub fn rooted_forest(&mut self, parent: &[usize]) -> Vec<usize> {
+        let n = parent.len();
+        let mut children = vec![vec![]; n];
+        let mut order = Vec::with_capacity(n);
+        for (v, &p) in parent.iter().enumerate() {
+            if p == !0 {
+                order.push(v);
+            } else {
+                children[p].push(v);
+            }
+        }
+        let mut i = 0;
+        while i < order.len() {
+            let u = order[i];
+            i += 1;
+            order.extend(children[u].iter().cloned());
+        }
+        assert_eq!(order.len(), n, "parent must form a forest");
+        let mut id = vec![!0; n];
+        self.ids_by_order(&order, parent, &mut id);
+        id
+    }
+    /// ids of all subtrees of the tree rooted at `root`
+    pub fn rooted(&mut self, graph: &UndirectedSparseGraph, root: usize) -> Vec<usize> {
+        let (order, parent) = graph.tree_order(root);
+        let mut id = vec![!0; graph.vertices_size()];
+        self.ids_by_order(&order, &parent, &mut id);
+        id
+    }
+    /// id of the unrooted tree
+    pub fn unrooted(&mut self, graph: &UndirectedSparseGraph) -> usize {
+        let key = match graph.tree_center() {
+            TreeCenter::One(u) => vec![self.rooted(graph, u)[u]],
+            TreeCenter::Two(u, v) => {
+                let (a, b) = (self.rooted(graph, u)[v], self.rooted(graph, v)[u]);
+                vec![a.min(b), a.max(b)]
+            }
+        };
+        Self::intern(&mut self.unrooted, key)
+    }
+    /// (vertices, id of the unrooted tree) of each tree in a forest
+    pub fn unrooted_forest(&mut self, graph: &UndirectedSparseGraph) -> Vec<(Vec<usize>, usize)> {
+        let n = graph.vertices_size();
+        let mut index = vec![!0usize; n];
+        let mut res = vec![];
+        for r in graph.vertices() {
+            if index[r] != !0 {
+                continue;
+            }
+            let mut vs = vec![r];
+            index[r] = 0;
+            let mut edges = vec![];
+            let mut i = 0;
+            while i < vs.len() {
+                let u = vs[i];
+                i += 1;
+                for a in graph.adjacencies(u) {
+                    if index[a.to] == !0 {
+                        index[a.to] = vs.len();
+                        vs.push(a.to);
+                        edges.push((index[u], index[a.to]));
+                    }
+                }
+            }
+            let tree = UndirectedSparseGraph::from_edges(vs.len(), edges);
+            let id = self.unrooted(&tree);
+            res.push((vs, id));
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rand, tools::Xorshift, tree::MixedTree};
+    use std::collections::BTreeMap;
+
+    fn canonical(g: &UndirectedSparseGraph, u: usize, p: usize) -> String {
+        let mut ch: Vec<_> = g
+            .adjacencies(u)
+            .filter(|a| a.to != p)
+            .map(|a| canonical(g, a.to, u))
+            .collect();
+        ch.sort_unstable();
+        format!("({})", ch.concat())
+    }
+
+    fn canonical_unrooted(g: &UndirectedSparseGraph) -> String {
+        match g.tree_center() {
+            TreeCenter::One(u) => canonical(g, u, !0),
+            TreeCenter::Two(u, v) => {
+                let mut s = [canonical(g, u, v), canonical(g, v, u)];
+                s.sort_unstable();
+                s.join("-")
+            }
+        }
+    }
+
+    #[test]
+    fn test_rooted_tree_isomorphism() {
+        const Q: usize = 100;
+        const N: usize = 30;
+        let mut rng = Xorshift::default();
+        let mut iso = TreeIsomorphism::new();
+        let mut s2id = BTreeMap::new();
+        let mut id2s = BTreeMap::new();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N), root: (0..n));
+            let g = rng.gen(MixedTree(n));
+            let id = iso.rooted(&g, root);
+            let (_, parent) = g.tree_order(root);
+            assert_eq!(iso.rooted_forest(&parent), id);
+            for u in 0..n {
+                let s = canonical(&g, u, parent[u]);
+                assert_eq!(*s2id.entry(s.clone()).or_insert(id[u]), id[u]);
+                assert_eq!(*id2s.entry(id[u]).or_insert(s), canonical(&g, u, parent[u]));
+            }
+        }
+        assert_eq!(iso.rooted_classes(), s2id.len());
+    }
+
+    #[test]
+    fn test_unrooted_tree_isomorphism() {
+        const Q: usize = 100;
+        const N: usize = 10;
+        let mut rng = Xorshift::default();
+        let mut iso = TreeIsomorphism::new();
+        let mut s2id = BTreeMap::new();
+        let mut id2s = BTreeMap::new();
+        for _ in 0..Q {
+            rand!(rng, n: (1..=N * 3), k: (1..=3));
+            // forest of `k` trees
+            let mut edges = vec![];
+            let mut trees = vec![];
+            let mut offset = 0;
+            for i in 0..k {
+                let m = if i + 1 == k {
+                    n - offset
+                } else {
+                    rng.gen(0..=n - offset)
+                };
+                if m == 0 {
+                    continue;
+                }
+                let g = rng.gen(MixedTree(m));
+                edges.extend(g.edges.iter().map(|&(u, v)| (u + offset, v + offset)));
+                trees.push((offset, g));
+                offset += m;
+            }
+            let g = UndirectedSparseGraph::from_edges(n, edges);
+            let forest = iso.unrooted_forest(&g);
+            assert_eq!(forest.len(), trees.len());
+            for ((vs, id), (offset, tree)) in forest.iter().zip(trees.iter()) {
+                let mut vs = vs.clone();
+                vs.sort_unstable();
+                assert_eq!(
+                    vs,
+                    (*offset..offset + tree.vertices_size()).collect::<Vec<_>>()
+                );
+                assert_eq!(iso.unrooted(tree), *id);
+                let s = canonical_unrooted(tree);
+                assert_eq!(*s2id.entry(s.clone()).or_insert(*id), *id);
+                assert_eq!(*id2s.entry(*id).or_insert(s.clone()), s);
+            }
+        }
+        assert_eq!(iso.unrooted_classes(), s2id.len());
+    }
+}
@@ -9,8 +9,8 @@ pub use competitive::{
 pub fn vertex_add_path_sum(reader: impl Read, mut writer: impl Write) {
     let s = read_all_unchecked(reader);
     let mut scanner = Scanner::new(&s);
-    scan!(scanner, n, q, a: [i64; n], (mut graph, _): @TreeGraphScanner::<usize, ()>::new(n));
-    let hld = HeavyLightDecomposition::new(0, &mut graph);
+    scan!(scanner, n, q, a: [i64; n], (graph, _): @TreeGraphScanner::<usize, ()>::new(n));
+    let hld = HeavyLightDecomposition::new(0, &graph);
     let mut bit = BinaryIndexedTree::<AdditiveOperation<_>>::new(n);
     for (i, a) in a.iter().cloned().enumerate() {
         bit.update(hld.vidx[i], a);
@@ -0,0 +1,17 @@
+use competitive::prelude::*;
+#[doc(no_inline)]
+pub use competitive::{graph::UndirectedGraphScanner, tree::LevelAncestor};
+
+#[verify::library_checker("jump_on_tree")]
+pub fn jump_on_tree(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, n, q, (graph, _): @UndirectedGraphScanner::<usize, ()>::new(n, n - 1));
+    let la = LevelAncestor::from_tree(&graph, 0);
+    for (s, t, i) in scanner.iter::<(usize, usize, usize)>().take(q) {
+        match la.jump(s, t, i) {
+            Some(v) => writeln!(writer, "{}", v).ok(),
+            None => writeln!(writer, "-1").ok(),
+        };
+    }
+}
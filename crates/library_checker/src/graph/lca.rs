@@ -2,7 +2,7 @@ use competitive::prelude::*;
 #[doc(no_inline)]
 pub use competitive::{
     graph::UndirectedSparseGraph,
-    tree::{EulerTourForRichVertex, HeavyLightDecomposition, LcaMonoidDefaultId},
+    tree::{EulerTourForRichVertex, HeavyLightDecomposition, LcaMonoidDefaultId, LevelAncestor},
 };
 
 #[verify::library_checker("lca")]
@@ -25,9 +25,21 @@ pub fn lca_hld(reader: impl Read, mut writer: impl Write) {
     let mut scanner = Scanner::new(&s);
     scan!(scanner, n, q, p: [usize]);
     let edges = p.take(n - 1).enumerate().map(|(i, p)| (i + 1, p)).collect();
-    let mut graph = UndirectedSparseGraph::from_edges(n, edges);
-    let hld = HeavyLightDecomposition::new(0, &mut graph);
+    let graph = UndirectedSparseGraph::from_edges(n, edges);
+    let hld = HeavyLightDecomposition::new(0, &graph);
     for (u, v) in scanner.iter::<(usize, usize)>().take(q) {
         writeln!(writer, "{}", hld.lca(u, v)).ok();
     }
 }
+
+#[verify::library_checker("lca")]
+pub fn lca_level_ancestor(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, n, q, p: [usize]);
+    let parent: Vec<_> = Some(!0).into_iter().chain(p.take(n - 1)).collect();
+    let la = LevelAncestor::new(&parent);
+    for (u, v) in scanner.iter::<(usize, usize)>().take(q) {
+        writeln!(writer, "{}", la.lca(u, v)).ok();
+    }
+}
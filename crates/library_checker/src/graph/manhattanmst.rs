@@ -0,0 +1,15 @@
+#[doc(no_inline)]
+pub use competitive::graph::manhattan_minimum_spanning_tree;
+use competitive::prelude::*;
+
+#[verify::library_checker("manhattanmst")]
+pub fn manhattanmst(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, n, points: [(i64, i64); n]);
+    let (cost, edges) = manhattan_minimum_spanning_tree(&points);
+    writeln!(writer, "{}", cost).ok();
+    for (u, v) in edges {
+        writeln!(writer, "{} {}", u, v).ok();
+    }
+}
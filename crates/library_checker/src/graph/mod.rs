@@ -1,4 +1,8 @@
 pub mod bipartitematching;
 pub mod directedmst;
+pub mod jump_on_tree;
 pub mod lca;
+pub mod manhattanmst;
+pub mod rooted_tree_isomorphism_classification;
 pub mod scc;
+pub mod shortest_path;
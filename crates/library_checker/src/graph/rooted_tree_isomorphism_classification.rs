@@ -0,0 +1,15 @@
+use competitive::prelude::*;
+#[doc(no_inline)]
+pub use competitive::tree::TreeIsomorphism;
+
+#[verify::library_checker("rooted_tree_isomorphism_classification")]
+pub fn rooted_tree_isomorphism_classification(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, n, p: [usize]);
+    let parent: Vec<_> = Some(!0).into_iter().chain(p.take(n - 1)).collect();
+    let mut iso = TreeIsomorphism::new();
+    let id = iso.rooted_forest(&parent);
+    writeln!(writer, "{}", iso.rooted_classes()).ok();
+    iter_print!(writer, @it id);
+}
@@ -0,0 +1,24 @@
+use competitive::prelude::*;
+#[doc(no_inline)]
+pub use competitive::{
+    algebra::AdditiveOperation,
+    graph::{DirectedGraphScanner, OptionSp, ShortestPathExt},
+};
+
+#[verify::library_checker("shortest_path")]
+pub fn shortest_path(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, n, m, s, t, (graph, c): @DirectedGraphScanner::<usize, u64>::new(n, m));
+    let (cost, prev) =
+        graph.dijkstra_ss_with_prev::<OptionSp<AdditiveOperation<_>>, _>(s, &|eid| Some(c[eid]));
+    if let Some(x) = cost[t] {
+        let path = graph.restore_path(&prev, t);
+        writeln!(writer, "{} {}", x, path.len() - 1).ok();
+        for p in path.windows(2) {
+            writeln!(writer, "{} {}", p[0], p[1]).ok();
+        }
+    } else {
+        writeln!(writer, "-1").ok();
+    }
+}
@@ -0,0 +1,13 @@
+use competitive::prelude::*;
+#[doc(no_inline)]
+pub use competitive::{math::Matrix, num::montgomery::MInt998244353};
+
+#[verify::library_checker("characteristic_polynomial")]
+pub fn characteristic_polynomial(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, n, a: [[MInt998244353; n]; n]);
+    let a = Matrix::from_vec(a);
+    let p = a.characteristic_polynomial();
+    iter_print!(writer, @it p);
+}
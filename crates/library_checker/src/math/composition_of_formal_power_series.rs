@@ -0,0 +1,14 @@
+use competitive::prelude::*;
+#[doc(no_inline)]
+pub use competitive::{math::Fps998244353, num::montgomery::MInt998244353};
+
+#[verify::library_checker("composition_of_formal_power_series")]
+pub fn composition_of_formal_power_series(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, n, a: [MInt998244353; n], b: [MInt998244353; n]);
+    let f = Fps998244353::from_vec(a);
+    let g = Fps998244353::from_vec(b);
+    let h = f.compose(&g, n);
+    iter_print!(writer, @it h.data);
+}
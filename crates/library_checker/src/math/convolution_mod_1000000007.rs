@@ -1,7 +1,7 @@
 use competitive::prelude::*;
 #[doc(no_inline)]
 pub use competitive::{
-    math::{Convolve, ConvolveSteps, MIntConvolve},
+    math::{Convolve, ConvolveSteps, MIntConvolve, MIntConvolveFft},
     num::mint_basic::{MInt1000000007, Modulo1000000007},
 };
 
@@ -14,3 +14,13 @@ pub fn convolution_mod_1000000007(reader: impl Read, mut writer: impl Write) {
     let c = MIntConvolve::<Modulo1000000007>::convolve(a, b);
     iter_print!(writer, @it c);
 }
+
+#[verify::library_checker("convolution_mod_1000000007")]
+pub fn convolution_mod_1000000007_fft(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    type M = MInt1000000007;
+    scan!(scanner, n, m, a: [M; n], b: [M; m]);
+    let c = MIntConvolveFft::<Modulo1000000007>::convolve(a, b);
+    iter_print!(writer, @it c);
+}
@@ -0,0 +1,11 @@
+#[doc(no_inline)]
+pub use competitive::math::prime_count;
+use competitive::prelude::*;
+
+#[verify::library_checker("counting_primes")]
+pub fn counting_primes(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, n: u64);
+    writeln!(writer, "{}", prime_count(n)).ok();
+}
@@ -0,0 +1,17 @@
+use competitive::prelude::*;
+#[doc(no_inline)]
+pub use competitive::{math::Fps998244353, num::montgomery::MInt998244353};
+
+#[verify::library_checker("inv_of_polynomials")]
+pub fn inv_of_polynomials(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, n, m, f: [MInt998244353; n], g: [MInt998244353; m]);
+    let f = Fps998244353::from_vec(f);
+    let g = Fps998244353::from_vec(g);
+    if let Some(h) = f.inv_mod(&g) {
+        iter_print!(writer, h.length(); @it h.data);
+    } else {
+        iter_print!(writer, "-1");
+    }
+}
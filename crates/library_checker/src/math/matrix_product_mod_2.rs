@@ -0,0 +1,24 @@
+use competitive::prelude::*;
+#[doc(no_inline)]
+pub use competitive::{data_structure::BitSet, math::Gf2Matrix};
+
+#[verify::library_checker("matrix_product_mod_2")]
+pub fn matrix_product_mod_2(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, n, m, k, a: [Bytes; n], b: [Bytes; m]);
+    let parse = |rows: Vec<Vec<u8>>, w: usize| {
+        let mut mat = Gf2Matrix::zeros((rows.len(), w));
+        for (i, row) in rows.iter().enumerate() {
+            for (j, &c) in row.iter().enumerate() {
+                mat.set(i, j, c == b'1');
+            }
+        }
+        mat
+    };
+    let c = &parse(a, m) * &parse(b, k);
+    for row in c.data.iter() {
+        let s: String = (0..k).map(|j| if row.get(j) { '1' } else { '0' }).collect();
+        writeln!(writer, "{}", s).ok();
+    }
+}
@@ -1,19 +1,32 @@
+pub mod characteristic_polynomial;
+pub mod composition_of_formal_power_series;
+pub mod compositional_inverse_of_formal_power_series;
 pub mod convolution_mod;
 pub mod convolution_mod_1000000007;
+pub mod counting_primes;
 pub mod discrete_logarithm_mod;
 pub mod enumerate_primes;
 pub mod exp_of_formal_power_series;
 pub mod factorize;
 pub mod find_linear_recurrence;
 pub mod inv_of_formal_power_series;
+pub mod inv_of_polynomials;
 pub mod kth_term_of_linearly_recurrent_sequence;
 pub mod log_of_formal_power_series;
+pub mod matrix_product;
+pub mod matrix_product_mod_2;
 pub mod min_of_mod_of_linear;
+pub mod multiplication_of_big_integers;
 pub mod multipoint_evaluation;
+pub mod polynomial_interpolation;
 pub mod polynomial_taylor_shift;
 pub mod pow_of_formal_power_series;
 pub mod sharp_p_subset_sum;
+pub mod shift_of_sampling_points_of_polynomial;
+pub mod sparse_matrix_det;
 pub mod sqrt_mod;
 pub mod sqrt_of_formal_power_series;
 pub mod sum_of_floor_of_linear;
+pub mod system_of_linear_equations;
+pub mod system_of_linear_equations_mod_2;
 pub mod two_sat;
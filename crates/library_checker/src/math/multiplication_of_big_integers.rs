@@ -0,0 +1,13 @@
+#[doc(no_inline)]
+pub use competitive::num::BigInt;
+use competitive::prelude::*;
+
+#[verify::library_checker("multiplication_of_big_integers")]
+pub fn multiplication_of_big_integers(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, t);
+    for (a, b) in scanner.iter::<(BigInt, BigInt)>().take(t) {
+        writeln!(writer, "{}", a * b).ok();
+    }
+}
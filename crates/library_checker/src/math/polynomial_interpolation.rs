@@ -0,0 +1,12 @@
+use competitive::prelude::*;
+#[doc(no_inline)]
+pub use competitive::{math::Fps998244353, num::montgomery::MInt998244353};
+
+#[verify::library_checker("polynomial_interpolation")]
+pub fn polynomial_interpolation(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, n, x: [MInt998244353; n], y: [MInt998244353; n]);
+    let f = Fps998244353::interpolation(&x, &y);
+    iter_print!(writer, @it f.data);
+}
@@ -0,0 +1,16 @@
+use competitive::prelude::*;
+#[doc(no_inline)]
+pub use competitive::{
+    math::{Fps998244353, MemorizedFactorial},
+    num::montgomery::MInt998244353,
+};
+
+#[verify::library_checker("shift_of_sampling_points_of_polynomial")]
+pub fn shift_of_sampling_points_of_polynomial(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, n, m, c, a: [MInt998244353; n]);
+    let f = MemorizedFactorial::new(n);
+    let res = Fps998244353::shift_of_sampling_points_of_polynomial(&a, c, m, &f);
+    iter_print!(writer, @it res);
+}
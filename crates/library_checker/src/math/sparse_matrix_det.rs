@@ -0,0 +1,12 @@
+use competitive::prelude::*;
+#[doc(no_inline)]
+pub use competitive::{math::SparseMatrix, num::montgomery::MInt998244353};
+
+#[verify::library_checker("sparse_matrix_det")]
+pub fn sparse_matrix_det(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, n, k, nonzero: [(usize, usize, MInt998244353); k]);
+    let a = SparseMatrix::from_nonzero((n, n), nonzero);
+    iter_print!(writer, a.determinant());
+}
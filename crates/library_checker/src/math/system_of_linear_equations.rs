@@ -0,0 +1,20 @@
+use competitive::prelude::*;
+#[doc(no_inline)]
+pub use competitive::{math::Matrix, num::montgomery::MInt998244353};
+
+#[verify::library_checker("system_of_linear_equations")]
+pub fn system_of_linear_equations(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, n, m, a: [[MInt998244353; m]; n], b: [MInt998244353; n]);
+    let a = Matrix::from_vec(a);
+    if let Some((x, basis)) = a.solve_system_of_linear_equations_with_kernel(&b) {
+        iter_print!(writer, basis.len());
+        iter_print!(writer, @it x);
+        for v in basis {
+            iter_print!(writer, @it v);
+        }
+    } else {
+        iter_print!(writer, "-1");
+    }
+}
@@ -0,0 +1,31 @@
+use competitive::prelude::*;
+#[doc(no_inline)]
+pub use competitive::{data_structure::BitSet, math::Gf2Matrix};
+
+#[verify::library_checker("system_of_linear_equations_mod_2")]
+pub fn system_of_linear_equations_mod_2(reader: impl Read, mut writer: impl Write) {
+    let s = read_all_unchecked(reader);
+    let mut scanner = Scanner::new(&s);
+    scan!(scanner, n, m, a: [Bytes; n], b: Bytes);
+    let mut mat = Gf2Matrix::zeros((n, m));
+    for (i, row) in a.iter().enumerate() {
+        for (j, &c) in row.iter().enumerate() {
+            mat.set(i, j, c == b'1');
+        }
+    }
+    let mut bs = BitSet::new(n);
+    for (i, &c) in b.iter().enumerate() {
+        bs.set(i, c == b'1');
+    }
+    let to_string =
+        |x: &BitSet| -> String { (0..m).map(|j| if x.get(j) { '1' } else { '0' }).collect() };
+    if let Some((x, basis)) = mat.solve_system_of_linear_equations_with_kernel(&bs) {
+        writeln!(writer, "{}", basis.len()).ok();
+        writeln!(writer, "{}", to_string(&x)).ok();
+        for v in basis.iter() {
+            writeln!(writer, "{}", to_string(v)).ok();
+        }
+    } else {
+        writeln!(writer, "-1").ok();
+    }
+}